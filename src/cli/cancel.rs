@@ -0,0 +1,15 @@
+//! `drover cancel` command - Cancel an in-flight run
+
+use clap::Parser;
+use crate::cli::find_project_dir;
+use crate::control::{self, RunCommand};
+
+#[derive(Parser, Debug)]
+pub struct CancelArgs {}
+
+pub async fn execute(_args: CancelArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    control::send_command(&control::socket_path(&project_dir), RunCommand::Cancel).await?;
+    println!("Cancelling - workers will drain and the run will checkpoint as cancelled.");
+    Ok(())
+}
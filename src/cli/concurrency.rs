@@ -0,0 +1,18 @@
+//! `drover concurrency` command - Live-adjust the number of active workers
+
+use clap::Parser;
+use crate::cli::find_project_dir;
+use crate::control::{self, RunCommand};
+
+#[derive(Parser, Debug)]
+pub struct ConcurrencyArgs {
+    /// New worker concurrency target (capped at the run's spawned pool size)
+    workers: usize,
+}
+
+pub async fn execute(args: ConcurrencyArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    control::send_command(&control::socket_path(&project_dir), RunCommand::SetWorkers(args.workers)).await?;
+    println!("Concurrency target set to {} - idle slots will pick it up immediately, busy ones after their current task.", args.workers);
+    Ok(())
+}
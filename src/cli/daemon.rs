@@ -0,0 +1,43 @@
+//! `drover daemon` command - run the Scheduler loop unattended
+
+use std::path::PathBuf;
+use std::time::Duration;
+use clap::Parser;
+
+use crate::cli::find_project_dir;
+use crate::config::{load_config, DroverConfig};
+use crate::durable::DurableStore;
+use crate::notifier::Notifier;
+use crate::scheduler::Scheduler;
+
+#[derive(Parser, Debug)]
+pub struct DaemonArgs {
+    /// Project directory
+    #[arg(short, long)]
+    project_dir: Option<PathBuf>,
+
+    /// How often to check for due schedules, in seconds
+    #[arg(long, default_value_t = 30)]
+    tick_interval: u64,
+}
+
+pub async fn execute(args: DaemonArgs) -> anyhow::Result<()> {
+    let project_dir = match args.project_dir {
+        Some(dir) => dir,
+        None => find_project_dir()?,
+    };
+
+    let config = load_config(&project_dir)?;
+    let notifier = Notifier::new(config.notify.clone());
+    let store = DurableStore::connect_with_notifier(&config.database, notifier).await?;
+    store.init().await?;
+
+    let drover_config: DroverConfig = config.into();
+    let drover_config = drover_config.with_project_dir(project_dir.clone());
+
+    println!("🐂 Drover daemon - watching {} every {}s", project_dir.display(), args.tick_interval);
+    println!("Add schedules with `drover schedule add`; list them with `drover schedule list`.");
+
+    let scheduler = Scheduler::new(project_dir, drover_config, store, Duration::from_secs(args.tick_interval));
+    scheduler.run().await
+}
@@ -0,0 +1,96 @@
+//! `drover logs` command - Replay or tail a task's captured output
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Parser;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::cli::find_project_dir;
+use crate::config::{load_config, DroverConfig};
+use crate::durable::DurableStore;
+use crate::workers::tasklog;
+
+#[derive(Parser, Debug)]
+pub struct LogsArgs {
+    /// Task ID to show logs for
+    task_id: String,
+
+    /// Keep the log open and print new output as it's written
+    #[arg(long)]
+    follow: bool,
+
+    /// Run ID to scope to (defaults to the most recent run)
+    #[arg(long = "run")]
+    run_id: Option<String>,
+}
+
+/// How often to poll a log file for new output while `--follow`ing.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub async fn execute(args: LogsArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    let config = load_config(&project_dir)?;
+
+    let store = DurableStore::connect(&config.database).await?;
+    store.init().await?;
+
+    let run_id = match args.run_id {
+        Some(id) => uuid::Uuid::parse_str(&id)?,
+        None => {
+            let runs = store.list_runs().await?;
+            match runs.first() {
+                Some(run) => run.id,
+                None => {
+                    println!("No runs found");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let drover_config: DroverConfig = config.into();
+    let run_dir = drover_config.log_dir.join(run_id.to_string());
+    let log_path = tasklog::log_path(&run_dir, &args.task_id);
+
+    if args.follow {
+        if !log_path.exists() {
+            anyhow::bail!("No log found for task {} in run {}", args.task_id, run_id);
+        }
+        follow(&log_path).await
+    } else {
+        let contents = tasklog::get_task_log(&run_dir, &args.task_id)?;
+        print!("{contents}");
+        Ok(())
+    }
+}
+
+/// Print the log's current contents, then keep polling for and printing
+/// whatever gets appended - like `tail -f`, but over a file a still-running
+/// worker is actively appending to.
+async fn follow(log_path: &PathBuf) -> anyhow::Result<()> {
+    let mut file = tokio::fs::File::open(log_path).await?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf).await?;
+    print!("{buf}");
+
+    let mut position = file.stream_position().await?;
+
+    loop {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+
+        let metadata = tokio::fs::metadata(log_path).await?;
+        if metadata.len() < position {
+            // The log was rotated out from under us - reopen from the start.
+            file = tokio::fs::File::open(log_path).await?;
+            position = 0;
+        }
+
+        let mut chunk = String::new();
+        file.read_to_string(&mut chunk).await?;
+        if !chunk.is_empty() {
+            print!("{chunk}");
+            position = file.stream_position().await?;
+        }
+    }
+}
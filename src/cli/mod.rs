@@ -1,9 +1,18 @@
 //! CLI command handlers
 
+pub mod cancel;
+pub mod concurrency;
+pub mod daemon;
+pub mod logs;
 pub mod muster;
+pub mod pause;
 pub mod resume;
 pub mod run;
+pub mod schedule;
 pub mod status;
+pub mod tranquility;
+pub mod worker;
+pub mod workers;
 
 use clap::Subcommand;
 use std::path::PathBuf;
@@ -21,6 +30,33 @@ pub enum Commands {
 
     /// Resume interrupted runs
     Resume(resume::ResumeArgs),
+
+    /// Show live status of every worker in a run
+    Workers(workers::WorkersArgs),
+
+    /// Query a live run's worker slots directly over its control socket
+    Worker(worker::WorkerArgs),
+
+    /// Pause an in-flight run
+    Pause(pause::PauseArgs),
+
+    /// Cancel an in-flight run
+    Cancel(cancel::CancelArgs),
+
+    /// Live-adjust the tranquility throttle on an in-flight run
+    Tranquility(tranquility::TranquilityArgs),
+
+    /// Live-adjust the number of active workers on an in-flight run
+    Concurrency(concurrency::ConcurrencyArgs),
+
+    /// Replay or tail a task's captured output
+    Logs(logs::LogsArgs),
+
+    /// Manage recurring re-discovery schedules
+    Schedule(schedule::ScheduleArgs),
+
+    /// Run as a daemon, firing due schedules unattended
+    Daemon(daemon::DaemonArgs),
 }
 
 pub async fn handle_command(cmd: Commands) -> anyhow::Result<()> {
@@ -29,6 +65,15 @@ pub async fn handle_command(cmd: Commands) -> anyhow::Result<()> {
         Commands::Status(args) => status::execute(args).await,
         Commands::Run(args) => run::execute(args).await,
         Commands::Resume(args) => resume::execute(args).await,
+        Commands::Workers(args) => workers::execute(args).await,
+        Commands::Worker(args) => worker::execute(args).await,
+        Commands::Pause(args) => pause::execute(args).await,
+        Commands::Cancel(args) => cancel::execute(args).await,
+        Commands::Tranquility(args) => tranquility::execute(args).await,
+        Commands::Concurrency(args) => concurrency::execute(args).await,
+        Commands::Logs(args) => logs::execute(args).await,
+        Commands::Schedule(args) => schedule::execute(args).await,
+        Commands::Daemon(args) => daemon::execute(args).await,
     }
 }
 
@@ -88,6 +88,7 @@ fn print_task(task: &crate::drover::Task, show_deps: bool) {
         crate::drover::TaskStatus::Claimed => "◐",
         crate::drover::TaskStatus::InProgress => "◑",
         crate::drover::TaskStatus::Blocked => "⊘",
+        crate::drover::TaskStatus::RetryScheduled => "⏳",
         crate::drover::TaskStatus::Completed => "✓",
         crate::drover::TaskStatus::Failed => "✗",
     };
@@ -95,6 +96,7 @@ fn print_task(task: &crate::drover::Task, show_deps: bool) {
     let status_color = match task.status {
         crate::drover::TaskStatus::Ready => "\x1b[36m",  // Cyan
         crate::drover::TaskStatus::Blocked => "\x1b[33m", // Yellow
+        crate::drover::TaskStatus::RetryScheduled => "\x1b[35m", // Magenta
         crate::drover::TaskStatus::Completed => "\x1b[32m", // Green
         crate::drover::TaskStatus::Failed => "\x1b[31m",   // Red
         _ => "\x1b[0m",
@@ -110,4 +112,12 @@ fn print_task(task: &crate::drover::Task, show_deps: bool) {
     if show_deps && !task.blocked_by.is_empty() {
         println!("      └─ blocked by: {:?}", task.blocked_by);
     }
+
+    if let Some(next_retry_at) = task.next_retry_at {
+        println!("      └─ retrying at {}", next_retry_at.to_rfc3339());
+    }
+
+    if task.critical_path_rank > 1 {
+        println!("      └─ critical path: {} task(s) deep", task.critical_path_rank);
+    }
 }
@@ -0,0 +1,15 @@
+//! `drover pause` command - Pause an in-flight run
+
+use clap::Parser;
+use crate::cli::find_project_dir;
+use crate::control::{self, RunCommand};
+
+#[derive(Parser, Debug)]
+pub struct PauseArgs {}
+
+pub async fn execute(_args: PauseArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    control::send_command(&control::socket_path(&project_dir), RunCommand::Pause).await?;
+    println!("Paused - workers will finish their current task and wait.");
+    Ok(())
+}
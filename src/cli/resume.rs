@@ -1,18 +1,36 @@
 //! `drover resume` command - Resume interrupted runs
 
+use std::time::Duration;
+
 use clap::Parser;
 use crate::cli::find_project_dir;
-use crate::durable::DurableStore;
+use crate::drover::{Drover, DroverResult, TaskStatus};
+use crate::durable::{DurableStore, TaskState};
 use crate::config::load_config;
 
 #[derive(Parser, Debug)]
 pub struct ResumeArgs {
-    /// Run ID to resume (omit to list)
+    /// Run ID to resume (omit to list) - checkpoint resume
     run_id: Option<String>,
+
+    /// Unpause a currently running (paused) `drover run` process over its
+    /// control socket, instead of resuming from a checkpoint
+    #[arg(long)]
+    live: bool,
 }
 
 pub async fn execute(args: ResumeArgs) -> anyhow::Result<()> {
     let project_dir = find_project_dir()?;
+
+    if args.live {
+        crate::control::send_command(
+            &crate::control::socket_path(&project_dir),
+            crate::control::RunCommand::Resume,
+        ).await?;
+        println!("Resumed - workers will start claiming tasks again.");
+        return Ok(());
+    }
+
     let config = load_config(&project_dir)?;
 
     let store = DurableStore::connect(&config.database).await?;
@@ -35,9 +53,95 @@ pub async fn execute(args: ResumeArgs) -> anyhow::Result<()> {
         return Ok(());
     }
 
+    let (mut manifest, config_snapshot) = store.load_run_snapshot(&run_id).await?
+        .ok_or_else(|| anyhow::anyhow!("No checkpointed manifest/config for run {} - it predates checkpoint resume", run_id))?;
+
+    // A worker that crashed mid-task never gets to call back in, so its
+    // claim can be left behind at `running` forever. Flip anything like
+    // that back to `ready` before reconciling against recorded state below,
+    // so a dead worker's stale claim isn't misread as still in flight.
+    let task_timeout = Duration::from_secs(config_snapshot.task_timeout_secs);
+    store.reclaim_stale_tasks(&run_id, task_timeout).await?;
+
+    // Reconcile each task against its last recorded state: `finished` stays
+    // completed, `error` stays failed (not silently forgotten), and
+    // anything else (pending/ready/running when the crash hit) is still
+    // outstanding and goes back to `ready` with its attempt count restored.
+    let task_records = store.all_task_records(&run_id).await?;
+    let mut outstanding = 0usize;
+    let mut completed_count = 0u32;
+    let mut failed_count = 0u32;
+
+    for task in manifest.all_tasks_mut() {
+        let Some(record) = task_records.iter().find(|r| r.task_id == task.id) else {
+            continue;
+        };
+
+        match record.state {
+            TaskState::Finished => {
+                task.status = TaskStatus::Completed;
+                completed_count += 1;
+            }
+            TaskState::Error => {
+                task.status = TaskStatus::Failed;
+                task.last_error = record.error.clone();
+                failed_count += 1;
+            }
+            TaskState::Pending | TaskState::Ready | TaskState::Running => {
+                task.attempts = record.attempt.max(0) as u32;
+                match record.next_retry_at {
+                    Some(next_retry_at) if next_retry_at > chrono::Utc::now() => {
+                        task.status = TaskStatus::RetryScheduled;
+                        task.next_retry_at = Some(next_retry_at);
+                    }
+                    _ => {
+                        task.status = TaskStatus::Ready;
+                        task.next_retry_at = None;
+                    }
+                }
+                outstanding += 1;
+            }
+        }
+    }
+
     println!("Resuming run {} started at {}", run_id, run.started_at);
-    println!("Note: Full resume not yet implemented");
-    println!("Run a new `drover run` to continue processing tasks");
+    println!("{} task(s) outstanding", outstanding);
+
+    if outstanding == 0 {
+        // Nothing left to drive, but the run row was never marked
+        // completed (the process died before it got there) - persist a
+        // terminal summary now so this run stops showing as "In Progress"
+        // forever.
+        let result = DroverResult {
+            success: failed_count == 0,
+            duration: Duration::ZERO,
+            tasks_completed: completed_count,
+            tasks_failed: failed_count,
+            success_rate: if completed_count + failed_count > 0 {
+                completed_count as f32 / (completed_count + failed_count) as f32
+            } else {
+                1.0
+            },
+            blockers: vec![],
+        };
+        store.complete_run(&run_id, &result).await?;
+        println!("All tasks already finished - nothing to resume.");
+        return Ok(());
+    }
+
+    // Prefer the tranquility a worker was actually heartbeating with over
+    // the value the run started with - `drover tranquility` may have
+    // live-adjusted it after the crash's last checkpoint.
+    let mut config_snapshot = config_snapshot;
+    if let Some(latest) = store.worker_statuses(&run_id).await?.into_iter().max_by_key(|w| w.last_seen) {
+        config_snapshot.tranquility = latest.tranquility;
+    }
+
+    let drover_config = config_snapshot.into_drover_config(project_dir, None);
+    let mut drover = Drover::resume(run_id, manifest, drover_config, store, completed_count, failed_count);
+    let result = drover.run().await?;
+
+    super::run::print_results(&result);
 
     Ok(())
 }
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use crate::config::{load_config, Config, DroverConfig};
 use crate::drover::{self, Drover};
 use crate::durable::DurableStore;
+use crate::notifier::Notifier;
 use crate::cli::find_project_dir;
 
 #[derive(Parser, Debug)]
@@ -86,7 +87,8 @@ pub async fn execute(args: RunArgs) -> anyhow::Result<()> {
     }
 
     // Initialize durable store
-    let store = DurableStore::connect(&config.database).await?;
+    let notifier = Notifier::new(config.notify.clone());
+    let store = DurableStore::connect_with_notifier(&config.database, notifier).await?;
     store.init().await?;
 
     // Build drover config
@@ -110,7 +112,7 @@ pub async fn execute(args: RunArgs) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn print_results(result: &drover::DroverResult) {
+pub(crate) fn print_results(result: &drover::DroverResult) {
     println!();
     if result.success {
         println!("✅ SUCCESS");
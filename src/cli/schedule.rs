@@ -0,0 +1,76 @@
+//! `drover schedule` command - manage recurring re-discovery schedules
+
+use std::time::Duration;
+use clap::{Parser, Subcommand};
+
+use crate::cli::find_project_dir;
+use crate::config::load_config;
+use crate::durable::DurableStore;
+
+#[derive(Parser, Debug)]
+pub struct ScheduleArgs {
+    #[command(subcommand)]
+    command: ScheduleCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ScheduleCommand {
+    /// Add a recurring re-discovery schedule
+    Add {
+        /// Filter by epic ID (the whole project if omitted)
+        #[arg(short, long)]
+        epic: Option<String>,
+
+        /// Re-discovery interval, in seconds
+        #[arg(short, long)]
+        interval: u64,
+
+        /// Fire even when discover_work finds no ready tasks
+        #[arg(long)]
+        always_fire: bool,
+    },
+
+    /// List every schedule and its next fire time
+    List,
+}
+
+pub async fn execute(args: ScheduleArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    let config = load_config(&project_dir)?;
+
+    let store = DurableStore::connect(&config.database).await?;
+    store.init().await?;
+
+    match args.command {
+        ScheduleCommand::Add { epic, interval, always_fire } => {
+            let id = store.create_schedule(epic.as_deref(), Duration::from_secs(interval), !always_fire).await?;
+            let target = epic.as_deref().map(|e| format!("epic {}", e)).unwrap_or_else(|| "the whole project".to_string());
+            println!("Created schedule {} - re-discovering {} every {}s", id, target, interval);
+        }
+
+        ScheduleCommand::List => {
+            let schedules = store.list_schedules().await?;
+
+            if schedules.is_empty() {
+                println!("No schedules configured - add one with `drover schedule add --interval <secs>`");
+                return Ok(());
+            }
+
+            println!("{:<38} {:<20} {:<10} {:<8} {:<9} {}", "ID", "TARGET", "INTERVAL", "ACTIVE", "REQUIRE", "NEXT FIRE");
+
+            for s in schedules {
+                println!(
+                    "{:<38} {:<20} {:<10} {:<8} {:<9} {}",
+                    s.id,
+                    s.epic_filter.as_deref().unwrap_or("(whole project)"),
+                    format!("{}s", s.interval.as_secs()),
+                    s.active,
+                    s.require_ready,
+                    s.next_fire_at.to_rfc3339(),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
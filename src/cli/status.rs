@@ -3,6 +3,8 @@
 use clap::Parser;
 use crate::drover;
 use crate::cli::find_project_dir;
+use crate::config::load_config;
+use crate::durable::DurableStore;
 
 #[derive(Parser, Debug)]
 pub struct StatusArgs {
@@ -32,7 +34,11 @@ pub async fn execute(args: StatusArgs) -> anyhow::Result<()> {
 }
 
 async fn show_status(project_dir: &std::path::PathBuf, epic: Option<&str>) -> anyhow::Result<()> {
-    let status = drover::get_project_status(project_dir, epic).await?;
+    let config = load_config(project_dir)?;
+    let store = DurableStore::connect(&config.database).await?;
+    store.init().await?;
+
+    let status = drover::get_project_status(project_dir, epic, Some(&store)).await?;
 
     println!("🐂 Drover Status\n");
     println!("  Total:   {}", status.total);
@@ -60,5 +66,9 @@ async fn show_status(project_dir: &std::path::PathBuf, epic: Option<&str>) -> an
         println!("  ETA: {:?}", eta);
     }
 
+    if let Some(next_fire) = status.next_scheduled_fire {
+        println!("\n  Next scheduled run: {}", next_fire.to_rfc3339());
+    }
+
     Ok(())
 }
@@ -0,0 +1,18 @@
+//! `drover tranquility` command - Live-adjust the tranquility throttle
+
+use clap::Parser;
+use crate::cli::find_project_dir;
+use crate::control::{self, RunCommand};
+
+#[derive(Parser, Debug)]
+pub struct TranquilityArgs {
+    /// New tranquility multiplier (0 = full speed, 4 = sleep 4x task duration)
+    value: f64,
+}
+
+pub async fn execute(args: TranquilityArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    control::send_command(&control::socket_path(&project_dir), RunCommand::SetTranquility(args.value)).await?;
+    println!("Tranquility set to {} - workers will pick it up after their current task.", args.value);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! `drover worker list` command - query live per-worker status over the
+//! control socket, for the run currently in progress (see `drover workers`
+//! for the durable, cross-run heartbeat view instead).
+
+use clap::{Parser, Subcommand};
+use crate::cli::find_project_dir;
+use crate::control;
+
+#[derive(Parser, Debug)]
+pub struct WorkerArgs {
+    #[command(subcommand)]
+    command: WorkerCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum WorkerCommand {
+    /// List every worker slot's live status, task counts, and last error
+    List,
+}
+
+pub async fn execute(args: WorkerArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+
+    match args.command {
+        WorkerCommand::List => {
+            let workers = control::query_workers(&control::socket_path(&project_dir)).await?;
+
+            if workers.is_empty() {
+                println!("No workers have claimed a task yet - is a `drover run` in progress?");
+                return Ok(());
+            }
+
+            println!("{:<12} {:<8} {:<20} {:<6} {:<7} {}", "WORKER", "STATUS", "TASK", "DONE", "FAILED", "LAST ERROR");
+
+            for w in workers {
+                println!(
+                    "{:<12} {:<8} {:<20} {:<6} {:<7} {}",
+                    w.worker_id,
+                    format!("{:?}", w.status).to_lowercase(),
+                    w.current_task.as_deref().unwrap_or("-"),
+                    w.tasks_completed,
+                    w.tasks_failed,
+                    w.last_error.as_deref().unwrap_or("-"),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
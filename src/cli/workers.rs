@@ -0,0 +1,83 @@
+//! `drover workers` command - Live introspection into worker slots
+
+use clap::Parser;
+use crate::cli::find_project_dir;
+use crate::config::load_config;
+use crate::durable::DurableStore;
+
+/// How long a worker's heartbeat can go quiet before it's considered dead.
+const DEAD_THRESHOLD_SECS: i64 = 30;
+
+#[derive(Parser, Debug)]
+pub struct WorkersArgs {
+    /// Run ID to inspect (defaults to the most recent run)
+    run_id: Option<String>,
+}
+
+pub async fn execute(args: WorkersArgs) -> anyhow::Result<()> {
+    let project_dir = find_project_dir()?;
+    let config = load_config(&project_dir)?;
+
+    let store = DurableStore::connect(&config.database).await?;
+    store.init().await?;
+
+    let run_id = match args.run_id {
+        Some(id) => uuid::Uuid::parse_str(&id)?,
+        None => {
+            let runs = store.list_runs().await?;
+            match runs.first() {
+                Some(run) => run.id,
+                None => {
+                    println!("No runs found");
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let workers = store.worker_statuses(&run_id).await?;
+
+    if workers.is_empty() {
+        println!("No workers have reported in for run {}", run_id);
+        return Ok(());
+    }
+
+    println!("🐂 Workers for run {}\n", run_id);
+    println!("{:<12} {:<8} {:<20} {:<10} {:<11}", "WORKER", "STATE", "TASK", "SINCE", "TRANQUILITY");
+
+    let now = chrono::Utc::now();
+
+    for worker in workers {
+        let idle_secs = (now - worker.last_seen).num_seconds();
+
+        let (label, since) = if idle_secs > DEAD_THRESHOLD_SECS {
+            ("dead", idle_secs)
+        } else if let Some(started_at) = worker.task_started_at {
+            ("active", (now - started_at).num_seconds())
+        } else {
+            ("idle", idle_secs)
+        };
+
+        println!(
+            "{:<12} {:<8} {:<20} {:<10} {:<11.2}",
+            worker.worker_id,
+            label,
+            worker.current_task.as_deref().unwrap_or("-"),
+            format_duration_secs(since),
+            worker.tranquility,
+        );
+    }
+
+    Ok(())
+}
+
+fn format_duration_secs(secs: i64) -> String {
+    let secs = secs.max(0);
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m{}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
@@ -36,9 +36,33 @@ pub struct Config {
     #[serde(default = "default_poll_interval")]
     pub poll_interval: u64,
 
+    /// Tranquility multiplier: after each task, a worker sleeps
+    /// `task_duration * tranquility` before claiming the next one. 0
+    /// disables the delay; higher values trade throughput for backpressure.
+    #[serde(default = "default_tranquility")]
+    pub tranquility: f64,
+
     /// Git worktree directory
     #[serde(default = "default_worktree_dir")]
     pub worktree_dir: Option<PathBuf>,
+
+    /// Directory task logs are captured under, one subdirectory per run
+    #[serde(default = "default_log_dir")]
+    pub log_dir: Option<PathBuf>,
+
+    /// Rotate a task's log once it reaches this many bytes
+    #[serde(default = "default_log_max_bytes")]
+    pub log_max_bytes: u64,
+
+    /// Number of gzip-compressed rotated generations to keep per task, in
+    /// addition to the current log file
+    #[serde(default = "default_log_max_backups")]
+    pub log_max_backups: u32,
+
+    /// Outbound notification targets, fired on run completion and on
+    /// terminal task failure
+    #[serde(default)]
+    pub notify: Vec<crate::notifier::NotifierConfig>,
 }
 
 impl Default for Config {
@@ -51,7 +75,12 @@ impl Default for Config {
             database: default_database(),
             stall_threshold: default_stall_threshold(),
             poll_interval: default_poll_interval(),
+            tranquility: default_tranquility(),
             worktree_dir: default_worktree_dir(),
+            log_dir: default_log_dir(),
+            log_max_bytes: default_log_max_bytes(),
+            log_max_backups: default_log_max_backups(),
+            notify: vec![],
         }
     }
 }
@@ -64,10 +93,14 @@ pub struct DroverConfig {
     pub task_timeout: Duration,
     pub stall_threshold: Duration,
     pub poll_interval: Duration,
+    pub tranquility: f64,
     pub auto_unblock: bool,
     pub project_dir: PathBuf,
     pub task_limit: Option<usize>,
     pub worktree_dir: PathBuf,
+    pub log_dir: PathBuf,
+    pub log_max_bytes: u64,
+    pub log_max_backups: u32,
 }
 
 impl From<Config> for DroverConfig {
@@ -78,10 +111,14 @@ impl From<Config> for DroverConfig {
             task_timeout: Duration::from_secs(config.timeout),
             stall_threshold: Duration::from_secs(config.stall_threshold),
             poll_interval: Duration::from_millis(config.poll_interval),
+            tranquility: config.tranquility,
             auto_unblock: config.auto_unblock,
             project_dir: PathBuf::from("."),
             task_limit: None,
             worktree_dir: config.worktree_dir.unwrap_or_else(|| PathBuf::from(".drover/worktrees")),
+            log_dir: config.log_dir.unwrap_or_else(|| PathBuf::from(".drover/logs")),
+            log_max_bytes: config.log_max_bytes,
+            log_max_backups: config.log_max_backups,
         }
     }
 }
@@ -98,6 +135,65 @@ impl DroverConfig {
     }
 }
 
+/// A serializable snapshot of the settings a run started with, persisted
+/// alongside it so `drover resume` can reconstruct the exact [`DroverConfig`]
+/// a crashed run used, rather than falling back to whatever `.drover.toml`
+/// says today.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroverConfigSnapshot {
+    pub max_workers: usize,
+    pub max_task_attempts: u32,
+    pub task_timeout_secs: u64,
+    pub stall_threshold_secs: u64,
+    pub poll_interval_ms: u64,
+    pub tranquility: f64,
+    pub auto_unblock: bool,
+    pub worktree_dir: PathBuf,
+    pub log_dir: PathBuf,
+    pub log_max_bytes: u64,
+    pub log_max_backups: u32,
+}
+
+impl From<&DroverConfig> for DroverConfigSnapshot {
+    fn from(config: &DroverConfig) -> Self {
+        Self {
+            max_workers: config.max_workers,
+            max_task_attempts: config.max_task_attempts,
+            task_timeout_secs: config.task_timeout.as_secs(),
+            stall_threshold_secs: config.stall_threshold.as_secs(),
+            poll_interval_ms: config.poll_interval.as_millis() as u64,
+            tranquility: config.tranquility,
+            auto_unblock: config.auto_unblock,
+            worktree_dir: config.worktree_dir.clone(),
+            log_dir: config.log_dir.clone(),
+            log_max_bytes: config.log_max_bytes,
+            log_max_backups: config.log_max_backups,
+        }
+    }
+}
+
+impl DroverConfigSnapshot {
+    /// Reconstruct a full [`DroverConfig`] for resuming this run, overlaying
+    /// the persisted settings onto a fresh `project_dir`/`task_limit`.
+    pub fn into_drover_config(self, project_dir: PathBuf, task_limit: Option<usize>) -> DroverConfig {
+        DroverConfig {
+            max_workers: self.max_workers,
+            max_task_attempts: self.max_task_attempts,
+            task_timeout: Duration::from_secs(self.task_timeout_secs),
+            stall_threshold: Duration::from_secs(self.stall_threshold_secs),
+            poll_interval: Duration::from_millis(self.poll_interval_ms),
+            tranquility: self.tranquility,
+            auto_unblock: self.auto_unblock,
+            project_dir,
+            task_limit,
+            worktree_dir: self.worktree_dir,
+            log_dir: self.log_dir,
+            log_max_bytes: self.log_max_bytes,
+            log_max_backups: self.log_max_backups,
+        }
+    }
+}
+
 /// Load configuration from .drover.toml in the current directory
 pub fn load_config(project_dir: &PathBuf) -> Result<Config> {
     let config_path = project_dir.join(".drover.toml");
@@ -123,7 +219,11 @@ fn default_auto_unblock() -> bool { true }
 fn default_database() -> String { "sqlite://.drover.db".to_string() }
 fn default_stall_threshold() -> u64 { 300 }
 fn default_poll_interval() -> u64 { 5000 }
+fn default_tranquility() -> f64 { 0.0 }
 fn default_worktree_dir() -> Option<PathBuf> { None }
+fn default_log_dir() -> Option<PathBuf> { None }
+fn default_log_max_bytes() -> u64 { 10 * 1024 * 1024 }
+fn default_log_max_backups() -> u32 { 5 }
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,198 @@
+//! Runtime control channel - pause, resume, and cancel an in-flight run
+//!
+//! A `drover run` process listens on a small JSON-line Unix socket
+//! (`.drover/control.sock`) for [`RunCommand`]s sent by `drover pause`,
+//! `drover resume --live`, `drover cancel`, and `drover tranquility`.
+//! Commands update a `watch::Sender<RunSignal>` that every worker
+//! subscribes to: on `Pause` workers finish the task they hold and then
+//! block before claiming another; on `Resume` they unblock; on `Cancel`
+//! they stop claiming entirely so the run can drain and checkpoint.
+//! `SetTranquility` instead updates a separate `watch::Sender<f64>` that
+//! feeds each worker's [`crate::workers::tranquility::Tranquilizer`].
+//! `SetWorkers` similarly updates a `watch::Sender<usize>` each worker slot
+//! polls before claiming - slots beyond the live count idle rather than
+//! claim, since the pool's task handles are fixed for the life of a run.
+//! `QueryWorkers` is the one command that gets a response: the listener
+//! writes a JSON line of [`crate::workers::WorkerInfoSnapshot`]s back on the
+//! same connection, so `drover worker list` can inspect live progress
+//! without reading the event stream.
+
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::watch;
+
+use crate::workers::{WorkerInfoSnapshot, WorkerState};
+
+/// A command sent to a running `drover run` process over its control socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum RunCommand {
+    Pause,
+    Resume,
+    Cancel,
+    SetWorkers(usize),
+    SetTranquility(f64),
+    /// Request a live snapshot of every worker slot - see the module docs.
+    QueryWorkers,
+}
+
+/// The run's current control state, broadcast to every worker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunSignal {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+/// Owns the watch channel workers subscribe to and the Unix socket listener
+/// that feeds it from external `drover pause`/`resume --live`/`cancel` calls.
+pub struct ControlChannel {
+    tx: watch::Sender<RunSignal>,
+    tranquility_tx: watch::Sender<f64>,
+    workers_tx: watch::Sender<usize>,
+    worker_state: WorkerState,
+}
+
+impl ControlChannel {
+    /// `tranquility` seeds the channel with the run's configured starting
+    /// value, so workers see it from their very first claim. `max_workers`
+    /// seeds the live concurrency target at the pool's spawned size.
+    /// `worker_state` answers `QueryWorkers` - it's the same handle the
+    /// pool's workers claim tasks through, so queries see live info.
+    pub fn new(tranquility: f64, max_workers: usize, worker_state: WorkerState) -> Self {
+        let (tx, _rx) = watch::channel(RunSignal::Running);
+        let (tranquility_tx, _rx) = watch::channel(tranquility);
+        let (workers_tx, _rx) = watch::channel(max_workers);
+        Self { tx, tranquility_tx, workers_tx, worker_state }
+    }
+
+    /// A receiver workers can poll/await to see the current signal.
+    pub fn subscribe(&self) -> watch::Receiver<RunSignal> {
+        self.tx.subscribe()
+    }
+
+    /// A receiver workers can poll to see the current tranquility
+    /// multiplier, updated live by `drover tranquility`.
+    pub fn subscribe_tranquility(&self) -> watch::Receiver<f64> {
+        self.tranquility_tx.subscribe()
+    }
+
+    /// A receiver workers can poll to see the live concurrency target,
+    /// updated by `RunCommand::SetWorkers`.
+    pub fn subscribe_concurrency(&self) -> watch::Receiver<usize> {
+        self.workers_tx.subscribe()
+    }
+
+    /// Bind `socket_path` and apply incoming commands to the watch channel
+    /// until the run ends. Removes any stale socket left by a crashed
+    /// previous run at the same path.
+    pub async fn listen(&self, socket_path: PathBuf) -> Result<tokio::task::JoinHandle<()>> {
+        let _ = std::fs::remove_file(&socket_path);
+        if let Some(parent) = socket_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let listener = UnixListener::bind(&socket_path)?;
+        let tx = self.tx.clone();
+        let tranquility_tx = self.tranquility_tx.clone();
+        let workers_tx = self.workers_tx.clone();
+        let worker_state = self.worker_state.clone();
+
+        let handle = tokio::spawn(async move {
+            loop {
+                let (stream, _) = match listener.accept().await {
+                    Ok(s) => s,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Control socket accept failed");
+                        continue;
+                    }
+                };
+
+                if let Err(e) = handle_connection(stream, &tx, &tranquility_tx, &workers_tx, &worker_state).await {
+                    tracing::warn!(error = %e, "Control connection failed");
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+}
+
+async fn handle_connection(
+    stream: UnixStream,
+    tx: &watch::Sender<RunSignal>,
+    tranquility_tx: &watch::Sender<f64>,
+    workers_tx: &watch::Sender<usize>,
+    worker_state: &WorkerState,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    if let Some(line) = lines.next_line().await? {
+        let command: RunCommand = serde_json::from_str(&line)?;
+
+        match command {
+            RunCommand::Pause => {
+                let _ = tx.send(RunSignal::Paused);
+            }
+            RunCommand::Resume => {
+                let _ = tx.send(RunSignal::Running);
+            }
+            RunCommand::Cancel => {
+                let _ = tx.send(RunSignal::Cancelled);
+            }
+            RunCommand::SetWorkers(n) => {
+                let _ = workers_tx.send(n.max(1));
+                // Scaling back up can make slots eligible again with no
+                // other trigger (no task completed, nothing newly `Ready`) -
+                // run a pass now so they don't sit idle until one happens.
+                worker_state.assign_ready_tasks().await;
+            }
+            RunCommand::SetTranquility(t) => {
+                let _ = tranquility_tx.send(t);
+            }
+            RunCommand::QueryWorkers => {
+                let snapshot = worker_state.worker_info_snapshot().await;
+                let payload = format!("{}\n", serde_json::to_string(&snapshot)?);
+                writer.write_all(payload.as_bytes()).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Send a single command to a running `drover run` process's control socket.
+pub async fn send_command(socket_path: &Path, command: RunCommand) -> Result<()> {
+    let mut stream = UnixStream::connect(socket_path)
+        .await
+        .map_err(|e| anyhow::anyhow!("No running drover process found at {}: {}", socket_path.display(), e))?;
+
+    let line = format!("{}\n", serde_json::to_string(&command)?);
+    stream.write_all(line.as_bytes()).await?;
+    Ok(())
+}
+
+/// Send a `QueryWorkers` command and read back the responding run's live
+/// worker snapshot.
+pub async fn query_workers(socket_path: &Path) -> Result<Vec<WorkerInfoSnapshot>> {
+    let stream = UnixStream::connect(socket_path)
+        .await
+        .map_err(|e| anyhow::anyhow!("No running drover process found at {}: {}", socket_path.display(), e))?;
+
+    let (reader, mut writer) = stream.into_split();
+    let line = format!("{}\n", serde_json::to_string(&RunCommand::QueryWorkers)?);
+    writer.write_all(line.as_bytes()).await?;
+
+    let mut lines = BufReader::new(reader).lines();
+    let response = lines.next_line().await?
+        .ok_or_else(|| anyhow::anyhow!("Control connection closed before sending a response"))?;
+    Ok(serde_json::from_str(&response)?)
+}
+
+/// Path to the control socket for a project directory.
+pub fn socket_path(project_dir: &Path) -> PathBuf {
+    project_dir.join(".drover").join("control.sock")
+}
@@ -16,7 +16,8 @@ use crossterm::{
 use std::io;
 use std::time::Duration;
 use anyhow::Result;
-use crate::drover::{ProjectStatus, WorkManifest};
+use crate::drover::{ProjectStatus, WorkManifest, WorkerLifecycle, WorkerStatusReport};
+use crate::durable::StuckTask;
 
 /// Dashboard for displaying real-time progress
 pub struct Dashboard {
@@ -35,10 +36,14 @@ impl Dashboard {
         Ok(Self { terminal })
     }
 
-    /// Update the dashboard with current status
-    pub fn update(&mut self, status: &ProjectStatus, manifest: &WorkManifest) -> Result<()> {
+    /// Update the dashboard with current status. `stuck_tasks` are tasks
+    /// that have been `running` longer than the stall threshold, surfaced
+    /// as a highlighted warning row (see [`crate::durable::DurableStore::poll_timing`]).
+    /// `workers` is a live snapshot of every worker slot (see
+    /// [`crate::drover::Drover::worker_status`]).
+    pub fn update(&mut self, status: &ProjectStatus, manifest: &WorkManifest, stuck_tasks: &[StuckTask], workers: &[WorkerStatusReport]) -> Result<()> {
         self.terminal.draw(|f| {
-            Self::render(f, status, manifest);
+            Self::render(f, status, manifest, stuck_tasks, workers);
         })?;
 
         // Check for quit event
@@ -53,7 +58,10 @@ impl Dashboard {
         Ok(())
     }
 
-    fn render(f: &mut Frame, status: &ProjectStatus, manifest: &WorkManifest) {
+    fn render(f: &mut Frame, status: &ProjectStatus, manifest: &WorkManifest, stuck_tasks: &[StuckTask], workers: &[WorkerStatusReport]) {
+        let stuck_height = if stuck_tasks.is_empty() { 0 } else { stuck_tasks.len().min(5) as u16 + 2 };
+        let workers_height = if workers.is_empty() { 0 } else { workers.len().min(8) as u16 + 2 };
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .margin(2)
@@ -61,6 +69,8 @@ impl Dashboard {
                 Constraint::Length(3),  // Header
                 Constraint::Length(3),  // Progress bar
                 Constraint::Length(10), // Stats
+                Constraint::Length(stuck_height), // Stuck task warnings
+                Constraint::Length(workers_height), // Worker slots
                 Constraint::Min(0),     // Task list
             ])
             .split(f.area());
@@ -113,12 +123,62 @@ impl Dashboard {
                 Span::styled("Failed:   ", Style::default().fg(Color::Gray)),
                 Span::styled(format!("{}", status.failed), Style::default().fg(Color::Red)),
             ]),
+            Line::from(vec![
+                Span::styled("Crit path:", Style::default().fg(Color::Gray)),
+                Span::styled(format!(" {} task(s) deep", critical_path_depth(manifest)), Style::default().fg(Color::Magenta)),
+            ]),
         ];
 
         let stats_block = Paragraph::new(stats)
             .block(Block::default().borders(Borders::ALL).title("Status"));
         f.render_widget(stats_block, chunks[2]);
 
+        // Stuck task warnings - tasks running longer than the stall threshold
+        if !stuck_tasks.is_empty() {
+            let warnings: Vec<Line> = stuck_tasks.iter().take(5).map(|t| {
+                Line::from(vec![
+                    Span::styled("⚠ ", Style::default().fg(Color::Red)),
+                    Span::styled(&t.task_id, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
+                    Span::raw(" running for "),
+                    Span::styled(format!("{:?}", t.running_for.to_std().unwrap_or_default()), Style::default().fg(Color::Yellow)),
+                ])
+            }).collect();
+
+            let warnings_block = Paragraph::new(warnings)
+                .block(Block::default().borders(Borders::ALL).title("⚠ Likely Stuck").style(Style::default().fg(Color::Red)));
+            f.render_widget(warnings_block, chunks[3]);
+        }
+
+        // Worker slots
+        if !workers.is_empty() {
+            let rows: Vec<Line> = workers.iter().take(8).map(|w| {
+                let (symbol, color) = match w.lifecycle {
+                    WorkerLifecycle::Active => ("●", Color::Green),
+                    WorkerLifecycle::Idle => ("○", Color::Gray),
+                    WorkerLifecycle::Dead => ("✕", Color::DarkGray),
+                };
+
+                let mut spans = vec![
+                    Span::styled(symbol, Style::default().fg(color)),
+                    Span::raw(" "),
+                    Span::styled(&w.worker_id, Style::default().fg(Color::White)),
+                ];
+                if let Some(task_id) = &w.current_task {
+                    spans.push(Span::raw(" running "));
+                    spans.push(Span::styled(task_id, Style::default().fg(Color::Cyan)));
+                }
+                if let Some(error) = &w.last_error {
+                    spans.push(Span::raw(" - "));
+                    spans.push(Span::styled(error, Style::default().fg(Color::Red)));
+                }
+                Line::from(spans)
+            }).collect();
+
+            let workers_block = Paragraph::new(rows)
+                .block(Block::default().borders(Borders::ALL).title("Workers"));
+            f.render_widget(workers_block, chunks[4]);
+        }
+
         // Epics list
         let epics_text: Vec<Line> = manifest.epics.iter().map(|epic| {
             let status_symbol = if epic.progress >= 100.0 {
@@ -144,7 +204,7 @@ impl Dashboard {
         let epics_block = Paragraph::new(epics_text)
             .block(Block::default().borders(Borders::ALL).title("Epics"))
             .wrap(Wrap { trim: true });
-        f.render_widget(epics_block, chunks[3]);
+        f.render_widget(epics_block, chunks[5]);
     }
 
     /// Clean up the terminal
@@ -166,6 +226,15 @@ impl Drop for Dashboard {
     }
 }
 
+/// The longest remaining critical-path chain across all tasks, i.e. the
+/// `critical_path_rank` of the deepest task still outstanding.
+fn critical_path_depth(manifest: &WorkManifest) -> i64 {
+    manifest.all_tasks().iter()
+        .map(|t| t.critical_path_rank)
+        .max()
+        .unwrap_or(0)
+}
+
 /// Simple non-TUI status printer
 pub fn print_status(status: &ProjectStatus, manifest: &WorkManifest) {
     let progress_bar_len = 40;
@@ -1,7 +1,7 @@
 // drover/mod.rs - Core orchestration logic
 // Discovers all work in a project and drives it to completion
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -11,10 +11,10 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use tracing::{info, warn, error, instrument};
 
-use crate::durable::DurableStore;
+use crate::durable::{DurableStore, TaskState};
 use crate::workers::{WorkerPool, WorkerEvent, WorkerState};
 use crate::dashboard::Dashboard;
-use crate::config::DroverConfig;
+use crate::config::{DroverConfig, DroverConfigSnapshot};
 
 // ============================================================================
 // TYPES
@@ -32,6 +32,97 @@ pub struct Task {
     pub labels: Vec<String>,
     pub attempts: u32,
     pub last_error: Option<String>,
+    /// Most recent step-count progress reported by this task while running,
+    /// if it emits any - see [`crate::workers::WorkerEvent::TaskProgress`].
+    #[serde(default)]
+    pub progress: Option<TaskProgress>,
+    /// Per-task override for how many attempts to allow before giving up.
+    /// Defaults to [`default_max_attempts`]; set via a `max-attempts:N` label.
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// When a retriable failure last scheduled this task to retry - set
+    /// while `status` is [`TaskStatus::RetryScheduled`], cleared once the
+    /// backoff elapses and it's requeued. See [`BackoffStrategy::delay`].
+    #[serde(default)]
+    pub next_retry_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Per-task override for the retry backoff curve. Defaults to
+    /// [`default_backoff`]; set via a `backoff:exp:<base_secs>:<factor>:<cap_secs>`
+    /// or `backoff:fixed:<secs>` label.
+    #[serde(default = "default_backoff")]
+    pub backoff: BackoffStrategy,
+    /// Length of the longest downstream chain of tasks blocked on this one,
+    /// computed by [`apply_critical_path_scheduling`]. The worker pool
+    /// claims the highest-ranked ready task first, so work on the critical
+    /// path for the whole run starts as early as possible.
+    #[serde(default)]
+    pub critical_path_rank: i64,
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+/// A task's most recently reported step-count progress.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskProgress {
+    pub completed: u64,
+    pub total: u64,
+    pub message: Option<String>,
+}
+
+/// How long to wait before retrying a task after a retriable failure.
+/// Modeled on `max_retries`-style policies: a curve plus a hard cap, so a
+/// persistently flaky task never waits longer than `cap`/`interval` between
+/// attempts.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum BackoffStrategy {
+    Exponential { base: Duration, factor: f64, cap: Duration },
+    Fixed(Duration),
+}
+
+impl BackoffStrategy {
+    /// Delay before the given 1-indexed attempt, plus jitter up to one
+    /// base/fixed unit so a batch of tasks that fail together (e.g. a rate
+    /// limit) don't all retry in lockstep.
+    fn delay(&self, attempt: u32) -> chrono::Duration {
+        let (raw, jitter_unit) = match *self {
+            BackoffStrategy::Exponential { base, factor, cap } => {
+                let exponent = attempt.saturating_sub(1).min(10) as i32;
+                (base.mul_f64(factor.powi(exponent)).min(cap), base)
+            }
+            BackoffStrategy::Fixed(interval) => (interval, interval),
+        };
+        let jitter = jitter_unit.mul_f64(rand::random::<f64>());
+        chrono::Duration::from_std(raw + jitter).unwrap_or_else(|_| chrono::Duration::seconds(RETRY_CAP.as_secs() as i64))
+    }
+}
+
+fn default_backoff() -> BackoffStrategy {
+    BackoffStrategy::Exponential { base: RETRY_BASE, factor: 2.0, cap: RETRY_CAP }
+}
+
+/// Parse a `backoff:fixed:<secs>` or `backoff:exp:<base_secs>:<factor>:<cap_secs>`
+/// label override, falling back to [`default_backoff`].
+fn parse_backoff(labels: &[String]) -> BackoffStrategy {
+    for label in labels {
+        if let Some(rest) = label.strip_prefix("backoff:fixed:") {
+            if let Ok(secs) = rest.parse::<u64>() {
+                return BackoffStrategy::Fixed(Duration::from_secs(secs));
+            }
+        } else if let Some(rest) = label.strip_prefix("backoff:exp:") {
+            if let [base, factor, cap] = rest.split(':').collect::<Vec<_>>()[..] {
+                if let (Ok(base), Ok(factor), Ok(cap)) = (base.parse::<u64>(), factor.parse::<f64>(), cap.parse::<u64>()) {
+                    return BackoffStrategy::Exponential {
+                        base: Duration::from_secs(base),
+                        factor,
+                        cap: Duration::from_secs(cap),
+                    };
+                }
+            }
+        }
+    }
+    default_backoff()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +132,8 @@ pub enum TaskStatus {
     Claimed,
     InProgress,
     Blocked,
+    /// Failed retriably and waiting out its backoff; see `next_retry_at`.
+    RetryScheduled,
     Completed,
     Failed,
 }
@@ -111,6 +204,57 @@ pub struct ProjectStatus {
     pub progress: f32,
     pub elapsed: Option<Duration>,
     pub eta: Option<Duration>,
+    /// Next time a [`crate::scheduler::Scheduler`] schedule is due to fire
+    /// for this project, if one is configured - `None` when `store` wasn't
+    /// passed to [`get_project_status`] or no schedule exists yet.
+    pub next_scheduled_fire: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A worker slot's lifecycle as of its last reported activity - see
+/// [`Drover::worker_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerLifecycle {
+    /// Holding and driving a task right now.
+    Active,
+    /// Idle between claims - either no ready work or waiting on pause.
+    Idle,
+    /// Exited its loop (no more work left to do for the run).
+    Dead,
+}
+
+/// A live snapshot of one worker slot, for in-process introspection of a
+/// running [`Drover`] - e.g. `run_with_dashboard` polling between frames.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkerStatusReport {
+    pub worker_id: String,
+    pub lifecycle: WorkerLifecycle,
+    pub current_task: Option<String>,
+    pub time_in_state: Duration,
+    pub last_error: Option<String>,
+}
+
+/// Map raw [`crate::workers::WorkerSnapshot`]s into [`WorkerStatusReport`]s -
+/// shared by [`Drover::worker_status`] and `run_with_dashboard`'s background
+/// polling loop, which reads a cloned [`crate::workers::WorkerManager`]
+/// handle directly rather than going through `self` (see that function's
+/// comments for why).
+fn worker_status_reports(snapshots: Vec<crate::workers::WorkerSnapshot>) -> Vec<WorkerStatusReport> {
+    snapshots.into_iter().map(|snapshot| {
+        let lifecycle = match snapshot.activity {
+            crate::workers::WorkerActivity::Busy => WorkerLifecycle::Active,
+            crate::workers::WorkerActivity::Idle => WorkerLifecycle::Idle,
+            crate::workers::WorkerActivity::Done => WorkerLifecycle::Dead,
+        };
+
+        WorkerStatusReport {
+            worker_id: snapshot.name,
+            lifecycle,
+            current_task: snapshot.status.current_task_id().map(str::to_string),
+            time_in_state: snapshot.since,
+            last_error: snapshot.status.last_error().map(str::to_string),
+        }
+    }).collect()
 }
 
 // ============================================================================
@@ -122,23 +266,117 @@ pub async fn discover_work(
     project_dir: &PathBuf,
     epic_filter: Option<&str>,
 ) -> anyhow::Result<WorkManifest> {
-    if let Some(epic_id) = epic_filter {
+    let mut manifest = if let Some(epic_id) = epic_filter {
         // Single epic mode
         let epic = load_epic(project_dir, epic_id).await?;
         let stats = calculate_stats(&epic.tasks);
 
-        Ok(WorkManifest {
+        WorkManifest {
             total_tasks: epic.tasks.len(),
             ready_tasks: stats.ready,
             blocked_tasks: stats.blocked,
             completed_tasks: stats.completed,
             epics: vec![epic],
             standalone_tasks: vec![],
-        })
+        }
     } else {
         // Full project mode - get all open work
-        load_all_work(project_dir).await
+        load_all_work(project_dir).await?
+    };
+
+    apply_critical_path_scheduling(&mut manifest);
+
+    Ok(manifest)
+}
+
+/// Minimum cost attributed to every task when computing critical-path
+/// weight - unit cost, so `critical_path_rank` reflects the length of the
+/// longest downstream chain rather than being skewed by priority.
+const CRITICAL_PATH_UNIT_COST: i64 = 1;
+
+/// Rank each task by the length of the longest downstream chain of tasks
+/// blocked on it, so the worker pool can claim the task that unblocks the
+/// most future work first rather than dispatching in arbitrary ready order.
+///
+/// Builds a DAG with an edge from each task in `blocked_by` to the task it
+/// blocks, runs Kahn's algorithm to get a topological order (and to detect
+/// cycles - any task left out of the order is part of one, and gets forced
+/// `Blocked` with a synthetic blocker so it surfaces via `handle_stall`
+/// instead of silently never running), then folds `critical_path_rank` up
+/// from sinks to sources over the reversed order.
+fn apply_critical_path_scheduling(manifest: &mut WorkManifest) {
+    let ids: Vec<String> = manifest.all_tasks().iter().map(|t| t.id.clone()).collect();
+    let id_set: HashSet<&str> = ids.iter().map(String::as_str).collect();
+
+    // children[blocker] = tasks that depend on it
+    let mut children: HashMap<String, Vec<String>> = HashMap::new();
+    let mut in_degree: HashMap<String, usize> = HashMap::new();
+
+    for task in manifest.all_tasks() {
+        let blockers: Vec<&String> = task.blocked_by.iter().filter(|b| id_set.contains(b.as_str())).collect();
+        in_degree.insert(task.id.clone(), blockers.len());
+        for blocker in blockers {
+            children.entry(blocker.clone()).or_default().push(task.id.clone());
+        }
     }
+
+    let mut queue: VecDeque<String> = in_degree.iter()
+        .filter(|(_, &d)| d == 0)
+        .map(|(id, _)| id.clone())
+        .collect();
+    let mut topo_order = Vec::with_capacity(ids.len());
+    let mut remaining = in_degree.clone();
+
+    while let Some(id) = queue.pop_front() {
+        topo_order.push(id.clone());
+        if let Some(kids) = children.get(&id) {
+            for child in kids {
+                if let Some(d) = remaining.get_mut(child) {
+                    *d -= 1;
+                    if *d == 0 {
+                        queue.push_back(child.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    // Anything left out of the topo order is part of a dependency cycle.
+    let ordered_set: HashSet<&String> = topo_order.iter().collect();
+    let cyclic: Vec<String> = ids.iter().filter(|id| !ordered_set.contains(id)).cloned().collect();
+    if !cyclic.is_empty() {
+        warn!(tasks = ?cyclic, "Dependency cycle detected in blocked_by graph - blocking affected tasks");
+        for task in manifest.all_tasks_mut() {
+            if cyclic.contains(&task.id) {
+                task.status = TaskStatus::Blocked;
+                task.blocked_by = vec!["drover-cycle-detected".to_string()];
+            }
+        }
+    }
+
+    // Fold critical-path weight up from sinks to sources: every node's
+    // children appear later in `topo_order`, so walking it in reverse
+    // guarantees a task's children are already ranked.
+    let mut cp: HashMap<String, i64> = HashMap::new();
+    for id in topo_order.iter().rev() {
+        let best_child = children.get(id)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| cp.get(c))
+            .max()
+            .copied()
+            .unwrap_or(0);
+        cp.insert(id.clone(), CRITICAL_PATH_UNIT_COST + best_child);
+    }
+
+    for task in manifest.all_tasks_mut() {
+        task.critical_path_rank = cp.get(&task.id).copied().unwrap_or(CRITICAL_PATH_UNIT_COST);
+    }
+
+    // Cycle-blocking above may have moved tasks from ready to blocked.
+    let stats = calculate_stats_from_refs(&manifest.all_tasks());
+    manifest.ready_tasks = stats.ready;
+    manifest.blocked_tasks = stats.blocked;
 }
 
 async fn load_epic(project_dir: &PathBuf, epic_id: &str) -> anyhow::Result<Epic> {
@@ -270,9 +508,12 @@ pub async fn list_all_tasks(project_dir: &PathBuf) -> anyhow::Result<Vec<Task>>
     Ok(tasks)
 }
 
+/// `store`, if given, is consulted for [`ProjectStatus::next_scheduled_fire`]
+/// - pass `None` when the caller has no [`DurableStore`] handle open.
 pub async fn get_project_status(
     project_dir: &PathBuf,
     epic: Option<&str>,
+    store: Option<&DurableStore>,
 ) -> anyhow::Result<ProjectStatus> {
     let manifest = discover_work(project_dir, epic).await?;
 
@@ -286,6 +527,11 @@ pub async fn get_project_status(
         100.0
     };
 
+    let next_scheduled_fire = match store {
+        Some(store) => store.next_scheduled_fire().await?,
+        None => None,
+    };
+
     Ok(ProjectStatus {
         total: manifest.total_tasks,
         completed: manifest.completed_tasks,
@@ -295,6 +541,7 @@ pub async fn get_project_status(
         progress,
         elapsed: None, // Would come from active run
         eta: None,
+        next_scheduled_fire,
     })
 }
 
@@ -308,6 +555,22 @@ pub struct Drover {
     store: DurableStore,
     run_id: Uuid,
     state: Arc<RwLock<DroverState>>,
+    /// Live worker activity, populated once [`Drover::run`] spawns its pool.
+    /// Shared with the pool rather than owned by it, so `worker_status` can
+    /// be polled from outside the run loop (e.g. by `run_with_dashboard`).
+    worker_manager: crate::workers::WorkerManager,
+    /// The scheduler `run` hands tasks to, set once at the top of the run.
+    /// Kept alongside `state` rather than folded into it since it's owned
+    /// and populated by [`WorkerPool`](crate::workers::WorkerPool), not
+    /// `Drover` itself - this is just `Drover`'s handle for pushing task
+    /// status changes (see [`WorkerState::set_task_status`]) into its
+    /// task-first scheduling.
+    worker_state: std::sync::OnceLock<WorkerState>,
+    /// The run's event channel, set once at the top of the run. Kept so
+    /// `handle_event` can synthesize follow-up events (e.g.
+    /// [`crate::workers::WorkerEvent::TaskDeadLettered`]) rather than acting
+    /// on them inline, the same way a worker itself would report them.
+    event_tx: std::sync::OnceLock<mpsc::Sender<WorkerEvent>>,
 }
 
 struct DroverState {
@@ -317,8 +580,14 @@ struct DroverState {
     failed_count: u32,
     last_progress: Instant,
     auto_created_tasks: HashSet<String>,
+    /// Last [`TASK_OUTPUT_RING_SIZE`] output lines per task, for post-mortem
+    /// inspection of what a task was doing - see [`Drover::task_output`].
+    task_output: HashMap<String, VecDeque<String>>,
 }
 
+/// How many trailing output lines to retain per task in [`DroverState::task_output`].
+const TASK_OUTPUT_RING_SIZE: usize = 200;
+
 impl Drover {
     pub async fn new(
         manifest: WorkManifest,
@@ -327,6 +596,41 @@ impl Drover {
     ) -> anyhow::Result<Self> {
         let run_id = Uuid::new_v4();
 
+        // Checkpoint initial state, including a snapshot of the settings
+        // this run started with so a crash can be resumed identically.
+        let snapshot = DroverConfigSnapshot::from(&config);
+        store.start_run(&run_id, &manifest, &snapshot).await?;
+
+        Ok(Self::from_parts(run_id, manifest, config, store, 0, 0))
+    }
+
+    /// Reconstruct a `Drover` for a run that was already started -
+    /// `drover resume` uses this to continue driving a crashed or
+    /// interrupted run, rather than re-seeding it as a new one.
+    ///
+    /// `completed_count`/`failed_count` seed the counters with whatever the
+    /// run had already finished before the crash, so the eventual
+    /// [`DroverResult`] reflects the whole run rather than just the
+    /// tasks that finish during this resumed session.
+    pub fn resume(
+        run_id: Uuid,
+        manifest: WorkManifest,
+        config: DroverConfig,
+        store: DurableStore,
+        completed_count: u32,
+        failed_count: u32,
+    ) -> Self {
+        Self::from_parts(run_id, manifest, config, store, completed_count, failed_count)
+    }
+
+    fn from_parts(
+        run_id: Uuid,
+        manifest: WorkManifest,
+        config: DroverConfig,
+        store: DurableStore,
+        completed_count: u32,
+        failed_count: u32,
+    ) -> Self {
         // Build task map
         let mut tasks = HashMap::new();
         for epic in &manifest.epics {
@@ -341,22 +645,53 @@ impl Drover {
         let state = DroverState {
             tasks,
             started_at: Instant::now(),
-            completed_count: 0,
-            failed_count: 0,
+            completed_count,
+            failed_count,
             last_progress: Instant::now(),
             auto_created_tasks: HashSet::new(),
+            task_output: HashMap::new(),
         };
 
-        // Checkpoint initial state
-        store.start_run(&run_id, &manifest).await?;
-
-        Ok(Self {
+        Self {
             manifest,
             config,
             store,
             run_id,
             state: Arc::new(RwLock::new(state)),
-        })
+            worker_manager: crate::workers::WorkerManager::new(),
+            worker_state: std::sync::OnceLock::new(),
+            event_tx: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// The task-first scheduler for the run currently in progress. Panics
+    /// if called before [`Self::run`] has set it up - every caller of this
+    /// is itself only reachable from within `run`'s event loop.
+    fn worker_state(&self) -> &WorkerState {
+        self.worker_state.get().expect("worker_state set at the top of run()")
+    }
+
+    /// The run's event channel, for synthesizing follow-up events from
+    /// inside `handle_event` itself. Panics if called before [`Self::run`]
+    /// has set it up, same as [`Self::worker_state`].
+    fn event_tx(&self) -> &mpsc::Sender<WorkerEvent> {
+        self.event_tx.get().expect("event_tx set at the top of run()")
+    }
+
+    /// A live snapshot of every worker slot's status - populated once
+    /// `run`/`run_with_dashboard` has spawned its pool, empty before then.
+    pub async fn worker_status(&self) -> Vec<WorkerStatusReport> {
+        worker_status_reports(self.worker_manager.snapshot().await)
+    }
+
+    /// The last [`TASK_OUTPUT_RING_SIZE`] lines of captured output for a
+    /// task, oldest first - empty if it hasn't produced any yet (or isn't
+    /// known to this run).
+    pub async fn task_output(&self, task_id: &str) -> Vec<String> {
+        let state = self.state.read().await;
+        state.task_output.get(task_id)
+            .map(|buf| buf.iter().cloned().collect())
+            .unwrap_or_default()
     }
 
     #[instrument(skip(self))]
@@ -370,27 +705,46 @@ impl Drover {
             .collect();
 
         let worker_state = WorkerState::new(all_tasks);
+        let _ = self.worker_state.set(worker_state.clone());
+
+        // Control channel - lets `drover pause`/`resume --live`/`cancel`
+        // steer this run from another process.
+        let control = crate::control::ControlChannel::new(self.config.tranquility, self.config.max_workers, worker_state.clone());
+        worker_state.set_concurrency(control.subscribe_concurrency());
+        let control_socket = crate::control::socket_path(&self.config.project_dir);
+        let control_listener = control.listen(control_socket.clone()).await?;
+        let mut control_rx = control.subscribe();
 
         // Create worker pool
         let (event_tx, mut event_rx) = mpsc::channel(1000);
+        let _ = self.event_tx.set(event_tx.clone());
         let pool_config = crate::workers::WorkerPoolConfig {
             max_workers: self.config.max_workers,
             task_timeout: self.config.task_timeout,
             project_dir: self.config.project_dir.clone(),
             worktree_dir: self.config.worktree_dir.clone(),
+            log_dir: self.config.log_dir.join(self.run_id.to_string()),
+            log_max_bytes: self.config.log_max_bytes,
+            log_max_backups: self.config.log_max_backups,
+            store: self.store.clone(),
+            run_id: self.run_id,
+            control: control.subscribe(),
+            tranquility: control.subscribe_tranquility(),
+            concurrency: control.subscribe_concurrency(),
         };
 
         let pool = WorkerPool::new(
             pool_config,
             worker_state,
-            event_tx,
+            event_tx.clone(),
+            self.worker_manager.clone(),
         )?;
 
         // Spawn workers
         let worker_handles = pool.spawn_workers().await?;
 
         // Spawn background tasks
-        let stall_detector = self.spawn_stall_detector();
+        let stall_detector = self.spawn_stall_detector(event_tx);
 
         let mut tasks_processed = 0;
 
@@ -411,10 +765,17 @@ impl Drover {
                 }
                 _ = tokio::time::sleep(self.config.poll_interval) => {
                     // Periodic check
+                    self.requeue_due_retries().await;
                     if self.is_complete().await {
                         break;
                     }
                 }
+                Ok(()) = control_rx.changed() => {
+                    if *control_rx.borrow() == crate::control::RunSignal::Cancelled {
+                        info!("Run cancelled via control channel");
+                        break;
+                    }
+                }
             }
 
             if self.is_complete().await {
@@ -422,6 +783,14 @@ impl Drover {
             }
         }
 
+        control_listener.abort();
+        let _ = std::fs::remove_file(&control_socket);
+
+        // Close every worker's assignment channel so any slot sitting idle
+        // on its channel wakes up with `Done` and exits on its own, rather
+        // than relying solely on the `abort()`s below.
+        self.worker_state().close().await;
+
         // Cleanup
         stall_detector.abort();
         for handle in worker_handles {
@@ -460,13 +829,120 @@ impl Drover {
         Ok(result)
     }
 
+    /// Like [`Self::run`], but renders a live TUI alongside it instead of
+    /// just logging - a background task refreshes a cloned copy of the
+    /// manifest from `self.state` (the same source `handle_event` itself
+    /// writes to) every tick and feeds it, a derived [`ProjectStatus`], and
+    /// [`crate::durable::DurableStore::poll_timing`]'s stuck-task list to
+    /// [`Dashboard::update`], so a stall is visible on screen instead of
+    /// only in the logs.
     pub async fn run_with_dashboard(&mut self) -> anyhow::Result<DroverResult> {
-        // For now, just run normally without TUI
-        self.run().await
+        let dashboard = Dashboard::new()?;
+
+        let mut manifest = self.manifest.clone();
+        let state = Arc::clone(&self.state);
+        let store = self.store.clone();
+        let run_id = self.run_id;
+        let stall_threshold = self.config.stall_threshold;
+        let started_at = Instant::now();
+        let worker_manager = self.worker_manager.clone();
+
+        let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel::<()>();
+
+        let dashboard_task = tokio::spawn(async move {
+            let mut dashboard = dashboard;
+
+            loop {
+                {
+                    let s = state.read().await;
+                    for task in manifest.all_tasks_mut() {
+                        if let Some(live) = s.tasks.get(&task.id) {
+                            task.status = live.status;
+                        }
+                    }
+                }
+                for epic in &mut manifest.epics {
+                    epic.progress = calculate_progress(&epic.tasks);
+                }
+
+                let all_tasks = manifest.all_tasks();
+                let stats = calculate_stats_from_refs(&all_tasks);
+                let failed = all_tasks.iter().filter(|t| t.status == TaskStatus::Failed).count();
+                let total = manifest.total_tasks;
+                let progress = if total > 0 {
+                    (stats.completed as f32 / total as f32) * 100.0
+                } else {
+                    100.0
+                };
+
+                let status = ProjectStatus {
+                    total,
+                    completed: stats.completed,
+                    ready: stats.ready,
+                    blocked: stats.blocked,
+                    failed,
+                    progress,
+                    elapsed: Some(started_at.elapsed()),
+                    eta: None,
+                    next_scheduled_fire: None,
+                };
+
+                let stuck_tasks = store.poll_timing(&run_id, stall_threshold).await.unwrap_or_default();
+                let workers = worker_status_reports(worker_manager.snapshot().await);
+
+                if dashboard.update(&status, &manifest, &stuck_tasks, &workers).is_err() {
+                    break;
+                }
+
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_millis(250)) => {}
+                    _ = &mut stop_rx => break,
+                }
+            }
+
+            dashboard
+        });
+
+        let result = self.run().await;
+
+        let _ = stop_tx.send(());
+        let _ = dashboard_task.await;
+
+        result
     }
 
     async fn handle_event(&mut self, event: WorkerEvent) -> anyhow::Result<()> {
         match event {
+            WorkerEvent::TaskClaimed { task_id, epic_id } => {
+                if let Err(e) = self.store.record_task_started(&self.run_id, &task_id, epic_id.as_deref(), None).await {
+                    warn!(task_id = %task_id, error = %e, "Failed to checkpoint task start");
+                }
+            }
+
+            WorkerEvent::TaskOutput { task_id, is_stderr, line } => {
+                if is_stderr {
+                    warn!(task_id = %task_id, "{}", line);
+                } else {
+                    info!(task_id = %task_id, "{}", line);
+                }
+
+                let mut state = self.state.write().await;
+                let buffer = state.task_output.entry(task_id).or_default();
+                buffer.push_back(line);
+                if buffer.len() > TASK_OUTPUT_RING_SIZE {
+                    buffer.pop_front();
+                }
+            }
+
+            WorkerEvent::TaskProgress { task_id, completed, total, message } => {
+                info!(task_id = %task_id, completed, total, message = ?message, "Task progress");
+
+                let mut state = self.state.write().await;
+                if let Some(task) = state.tasks.get_mut(&task_id) {
+                    task.progress = Some(TaskProgress { completed, total, message });
+                }
+            }
+
             WorkerEvent::TaskCompleted { task_id, duration } => {
                 info!(task_id = %task_id, duration = ?duration, "Task completed");
 
@@ -476,6 +952,13 @@ impl Drover {
                 }
                 state.completed_count += 1;
                 state.last_progress = Instant::now();
+                drop(state);
+
+                self.worker_state().set_task_status(&task_id, TaskStatus::Completed).await;
+
+                if let Err(e) = self.store.record_task_finished(&self.run_id, &task_id, TaskState::Finished, None).await {
+                    warn!(task_id = %task_id, error = %e, "Failed to checkpoint task completion");
+                }
 
                 // Update Beads
                 self.close_task(&task_id, "Completed by Drover").await?;
@@ -488,21 +971,54 @@ impl Drover {
                 warn!(task_id = %task_id, error = %error, "Task failed");
 
                 let mut state = self.state.write().await;
-                if let Some(task) = state.tasks.get_mut(&task_id) {
+                let outcome = if let Some(task) = state.tasks.get_mut(&task_id) {
                     task.attempts += 1;
                     task.last_error = Some(error.clone());
 
                     if retriable && task.attempts < self.config.max_task_attempts {
-                        task.status = TaskStatus::Ready;
-                        info!(task_id = %task_id, attempt = task.attempts, "Requeueing");
+                        let next_retry_at = chrono::Utc::now() + task.backoff.delay(task.attempts);
+                        task.status = TaskStatus::RetryScheduled;
+                        task.next_retry_at = Some(next_retry_at);
+                        info!(task_id = %task_id, attempt = task.attempts, %next_retry_at, "Scheduling retry with backoff");
+                        TaskFailureOutcome::RetryScheduled
                     } else {
                         task.status = TaskStatus::Failed;
                         state.failed_count += 1;
-                        error!(task_id = %task_id, "Task permanently failed");
+                        TaskFailureOutcome::DeadLettered { attempts: task.attempts }
                     }
+                } else {
+                    TaskFailureOutcome::Unknown
+                };
+                drop(state);
+
+                match outcome {
+                    TaskFailureOutcome::DeadLettered { attempts } => {
+                        // Routed through the event channel rather than
+                        // acting inline, so it's a distinct, observable
+                        // `WorkerEvent::TaskDeadLettered` - not just an
+                        // internal side effect of `TaskFailed` - and handled
+                        // in exactly one place below.
+                        let _ = self.event_tx().send(WorkerEvent::TaskDeadLettered {
+                            task_id: task_id.clone(),
+                            attempts,
+                            last_error: error.clone(),
+                        }).await;
+                    }
+                    TaskFailureOutcome::RetryScheduled => {
+                        self.worker_state().set_task_status(&task_id, TaskStatus::RetryScheduled).await;
+                        if let Err(e) = self.store.reschedule_task(&self.run_id, &task_id, &error, self.config.max_task_attempts).await {
+                            warn!(task_id = %task_id, error = %e, "Failed to checkpoint retry schedule");
+                        }
+                    }
+                    TaskFailureOutcome::Unknown => {}
                 }
             }
 
+            WorkerEvent::TaskDeadLettered { task_id, attempts, last_error } => {
+                self.worker_state().set_task_status(&task_id, TaskStatus::Failed).await;
+                self.dead_letter_task(&task_id, attempts, &last_error).await;
+            }
+
             WorkerEvent::TaskBlocked { task_id, blocked_by } => {
                 warn!(task_id = %task_id, blocked_by = ?blocked_by, "Task blocked");
 
@@ -513,6 +1029,8 @@ impl Drover {
                 }
                 drop(state);
 
+                self.worker_state().set_task_status(&task_id, TaskStatus::Blocked).await;
+
                 // Auto-unblock
                 if self.config.auto_unblock {
                     for blocker in blocked_by {
@@ -537,18 +1055,59 @@ impl Drover {
         })
     }
 
+    /// Requeue any [`TaskStatus::RetryScheduled`] task whose backoff has
+    /// elapsed, so the worker pool picks it back up on its next claim.
+    async fn requeue_due_retries(&self) {
+        let now = chrono::Utc::now();
+        let mut state = self.state.write().await;
+
+        let mut due = Vec::new();
+        for task in state.tasks.values_mut() {
+            if task.status == TaskStatus::RetryScheduled && task.next_retry_at.map_or(true, |at| at <= now) {
+                task.status = TaskStatus::Ready;
+                task.next_retry_at = None;
+                info!(task_id = %task.id, "Retry backoff elapsed, requeueing");
+                due.push(task.id.clone());
+            }
+        }
+        drop(state);
+
+        for task_id in due {
+            self.worker_state().set_task_status(&task_id, TaskStatus::Ready).await;
+        }
+    }
+
+    /// Checkpoint a task that has exhausted its retry policy as terminally
+    /// `Error`'d - distinct from [`Self::requeue_due_retries`]'s transient
+    /// reschedule path, so an operator can tell "still retrying" from
+    /// "needs a human" at a glance.
+    async fn dead_letter_task(&self, task_id: &str, attempts: u32, last_error: &str) {
+        error!(task_id = %task_id, attempts, error = %last_error, "Task dead-lettered after exhausting retries");
+
+        if let Err(e) = self.store.record_task_finished(&self.run_id, task_id, TaskState::Error, Some(last_error)).await {
+            warn!(task_id = %task_id, error = %e, "Failed to checkpoint dead-lettered task");
+        }
+    }
+
     async fn check_unblocked(&self, completed_id: &str) -> anyhow::Result<()> {
         let mut state = self.state.write().await;
 
+        let mut unblocked = Vec::new();
         for task in state.tasks.values_mut() {
             if task.status == TaskStatus::Blocked {
                 task.blocked_by.retain(|b| b != completed_id);
                 if task.blocked_by.is_empty() {
                     task.status = TaskStatus::Ready;
                     info!(task_id = %task.id, "Task unblocked");
+                    unblocked.push(task.id.clone());
                 }
             }
         }
+        drop(state);
+
+        for task_id in unblocked {
+            self.worker_state().set_task_status(&task_id, TaskStatus::Ready).await;
+        }
 
         Ok(())
     }
@@ -573,13 +1132,22 @@ impl Drover {
             labels: vec!["drover-auto".to_string()],
             attempts: 0,
             last_error: None,
+            progress: None,
+            max_attempts: default_max_attempts(),
+            next_retry_at: None,
+            backoff: default_backoff(),
+            critical_path_rank: 0,
         };
 
-        state.tasks.insert(task_id.clone(), task);
+        state.tasks.insert(task_id.clone(), task.clone());
         state.auto_created_tasks.insert(blocker.to_string());
+        drop(state);
+
+        // This task didn't exist when the pool's scheduler was seeded -
+        // push it in now so it's immediately eligible for assignment.
+        self.worker_state().push_task(task).await;
 
         // Create in Beads
-        drop(state);
         self.create_beads_task(&task_id, blocker).await?;
 
         info!(task_id = %task_id, blocker = %blocker, "Created unblock task");
@@ -627,8 +1195,12 @@ impl Drover {
         Ok(())
     }
 
-    fn spawn_stall_detector(&self) -> tokio::task::JoinHandle<()> {
+    /// Watch for the whole run making no progress, and actually report it -
+    /// `WorkerEvent::Stalled` used to be defined but never sent, so nothing
+    /// downstream of it (`handle_event`'s arm, `handle_stall`) ever ran.
+    fn spawn_stall_detector(&self, event_tx: mpsc::Sender<WorkerEvent>) -> tokio::task::JoinHandle<()> {
         let state = Arc::clone(&self.state);
+        let worker_state = self.worker_state().clone();
         let threshold = self.config.stall_threshold;
 
         tokio::spawn(async move {
@@ -636,8 +1208,20 @@ impl Drover {
                 tokio::time::sleep(Duration::from_secs(60)).await;
 
                 let s = state.read().await;
-                if s.last_progress.elapsed() > threshold {
-                    warn!(elapsed = ?s.last_progress.elapsed(), "Stall detected");
+                let ready = s.tasks.values().filter(|t| t.status == TaskStatus::Ready).count();
+                let blocked = s.tasks.values().filter(|t| t.status == TaskStatus::Blocked).count();
+                let elapsed = s.last_progress.elapsed();
+                drop(s);
+
+                // Every remaining task blocked with no worker holding
+                // anything is a genuine deadlock - nothing will ever change
+                // on its own, so don't wait out the full threshold to
+                // report it the way a merely slow run would.
+                let wedged = ready == 0 && blocked > 0 && worker_state.busy_workers().await.is_empty();
+
+                if wedged || elapsed > threshold {
+                    warn!(?elapsed, wedged, "Stall detected");
+                    let _ = event_tx.send(WorkerEvent::Stalled { duration: elapsed }).await;
                 }
             }
         })
@@ -689,10 +1273,42 @@ impl BeadItem {
             labels: self.labels,
             attempts: 0,
             last_error: None,
+            progress: None,
+            max_attempts: parse_max_attempts(&self.labels),
+            next_retry_at: None,
+            backoff: parse_backoff(&self.labels),
+            critical_path_rank: 0,
         }
     }
 }
 
+/// How a failed task was dispositioned, to decide what (if anything) gets
+/// checkpointed to the [`DurableStore`] once the in-memory state lock is
+/// released.
+enum TaskFailureOutcome {
+    RetryScheduled,
+    /// The task exhausted its retry policy - terminal, distinct from a
+    /// single transient failure. See [`Drover::dead_letter_task`].
+    DeadLettered { attempts: u32 },
+    /// The task wasn't found in memory (shouldn't happen in practice) - no
+    /// checkpoint to write.
+    Unknown,
+}
+
+/// Base delay used when scheduling a retriable task's next attempt.
+const RETRY_BASE: Duration = Duration::from_secs(10);
+/// Cap on the backoff delay so a persistently flaky task never waits longer
+/// than an hour between attempts.
+const RETRY_CAP: Duration = Duration::from_secs(3600);
+
+/// Parse a `max-attempts:N` label override, falling back to the default.
+fn parse_max_attempts(labels: &[String]) -> u32 {
+    labels.iter()
+        .find_map(|l| l.strip_prefix("max-attempts:"))
+        .and_then(|n| n.parse().ok())
+        .unwrap_or_else(default_max_attempts)
+}
+
 // ============================================================================
 // HELPERS
 // ============================================================================
@@ -726,3 +1342,77 @@ fn calculate_progress(tasks: &[Task]) -> f32 {
     let completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
     (completed as f32 / tasks.len() as f32) * 100.0
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_task(id: &str, blocked_by: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: None,
+            priority: 0,
+            status: TaskStatus::Ready,
+            parent_epic: None,
+            blocked_by: blocked_by.iter().map(|s| s.to_string()).collect(),
+            labels: vec![],
+            attempts: 0,
+            last_error: None,
+            progress: None,
+            max_attempts: default_max_attempts(),
+            next_retry_at: None,
+            backoff: default_backoff(),
+            critical_path_rank: 0,
+        }
+    }
+
+    fn manifest_of(tasks: Vec<Task>) -> WorkManifest {
+        let stats = calculate_stats(&tasks);
+        WorkManifest {
+            total_tasks: tasks.len(),
+            ready_tasks: stats.ready,
+            blocked_tasks: stats.blocked,
+            completed_tasks: stats.completed,
+            epics: vec![],
+            standalone_tasks: tasks,
+        }
+    }
+
+    #[test]
+    fn critical_path_ranks_a_linear_chain_deepest_first() {
+        let tasks = vec![
+            test_task("a", &[]),
+            test_task("b", &["a"]),
+            test_task("c", &["b"]),
+        ];
+        let mut manifest = manifest_of(tasks);
+        apply_critical_path_scheduling(&mut manifest);
+
+        let rank = |id: &str| manifest.all_tasks().iter().find(|t| t.id == id).unwrap().critical_path_rank;
+        assert!(rank("a") > rank("b"));
+        assert!(rank("b") > rank("c"));
+    }
+
+    #[test]
+    fn cyclic_blocked_by_graph_gets_blocked_instead_of_hanging() {
+        // a <- c <- b <- a: every task is blocked on one that's (transitively)
+        // blocked on it, so Kahn's algorithm can never drain its queue -
+        // these should come out `Blocked`, not silently dropped from
+        // scheduling or left to loop forever.
+        let tasks = vec![
+            test_task("a", &["c"]),
+            test_task("b", &["a"]),
+            test_task("c", &["b"]),
+        ];
+        let mut manifest = manifest_of(tasks);
+        apply_critical_path_scheduling(&mut manifest);
+
+        for task in manifest.all_tasks() {
+            assert_eq!(task.status, TaskStatus::Blocked);
+            assert_eq!(task.blocked_by, vec!["drover-cycle-detected".to_string()]);
+        }
+        assert_eq!(manifest.blocked_tasks, 3);
+        assert_eq!(manifest.ready_tasks, 0);
+    }
+}
@@ -1,30 +1,48 @@
 //! Durable execution - checkpointing and state persistence
 
-use std::path::Path;
-use sqlx::{Pool, Postgres, Sqlite, sqlite::SqlitePool, postgres::PgPool};
+use std::time::Duration;
+use sqlx::{sqlite::SqlitePool, postgres::PgPool};
 use uuid::Uuid;
 use anyhow::Result;
+use crate::config::DroverConfigSnapshot;
 use crate::drover::{WorkManifest, DroverResult};
+use crate::notifier::{Notifier, RunNotification, TaskFailureSummary};
 
-/// Durable store for checkpointing run state
+/// Durable store for checkpointing run state. Cheap to clone - the
+/// underlying connection pool is reference-counted - so worker tasks can
+/// each hold their own handle to report heartbeats concurrently.
+#[derive(Clone)]
 pub enum DurableStore {
     Sqlite(SqliteStore),
     Postgres(PostgresStore),
 }
 
 impl DurableStore {
-    /// Create a new store from a database URL
+    /// Create a new store from a database URL, with no outbound notifications.
     pub async fn connect(database_url: &str) -> Result<Self> {
+        Self::connect_with_notifier(database_url, Notifier::disabled()).await
+    }
+
+    /// Create a new store from a database URL, firing `notifier` on run
+    /// completion and on terminal task failure.
+    pub async fn connect_with_notifier(database_url: &str, notifier: Notifier) -> Result<Self> {
         if database_url.starts_with("sqlite://") {
             let path = database_url.trim_start_matches("sqlite://");
-            SqliteStore::new(path).await.map(DurableStore::Sqlite)
+            SqliteStore::new(path, notifier).await.map(DurableStore::Sqlite)
         } else if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
-            PostgresStore::new(database_url).await.map(DurableStore::Postgres)
+            PostgresStore::new(database_url, notifier).await.map(DurableStore::Postgres)
         } else {
             anyhow::bail!("Unsupported database URL: {}", database_url);
         }
     }
 
+    fn notifier(&self) -> &Notifier {
+        match self {
+            DurableStore::Sqlite(s) => &s.notifier,
+            DurableStore::Postgres(p) => &p.notifier,
+        }
+    }
+
     /// Initialize the database schema
     pub async fn init(&self) -> Result<()> {
         match self {
@@ -33,20 +51,51 @@ impl DurableStore {
         }
     }
 
-    /// Start a new run, recording initial state
-    pub async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest) -> Result<()> {
+    /// Start a new run, recording initial state and the [`DroverConfigSnapshot`]
+    /// it started with so a crash can be resumed with the same settings.
+    pub async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest, config: &DroverConfigSnapshot) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.start_run(run_id, manifest, config).await,
+            DurableStore::Postgres(p) => p.start_run(run_id, manifest, config).await,
+        }
+    }
+
+    /// Load the manifest and config snapshot a run was started with, so
+    /// `drover resume` can reconstruct it without rediscovering or
+    /// reconfiguring from scratch.
+    pub async fn load_run_snapshot(&self, run_id: &Uuid) -> Result<Option<(WorkManifest, DroverConfigSnapshot)>> {
         match self {
-            DurableStore::Sqlite(s) => s.start_run(run_id, manifest).await,
-            DurableStore::Postgres(p) => p.start_run(run_id, manifest).await,
+            DurableStore::Sqlite(s) => s.load_run_snapshot(run_id).await,
+            DurableStore::Postgres(p) => p.load_run_snapshot(run_id).await,
         }
     }
 
-    /// Record run completion
+    /// Record run completion. Completed/failed counts are derived from the
+    /// per-task rows rather than trusting the caller's in-memory tally.
     pub async fn complete_run(&self, run_id: &Uuid, result: &DroverResult) -> Result<()> {
         match self {
             DurableStore::Sqlite(s) => s.complete_run(run_id, result).await,
             DurableStore::Postgres(p) => p.complete_run(run_id, result).await,
-        }
+        }?;
+
+        let failed_tasks = self.all_task_records(run_id).await?
+            .into_iter()
+            .filter(|t| t.state == TaskState::Error)
+            .map(|t| TaskFailureSummary {
+                task_id: t.task_id,
+                error: t.error.unwrap_or_default(),
+            })
+            .collect();
+
+        self.notifier().notify_run_complete(RunNotification {
+            run_id: *run_id,
+            success: result.success,
+            tasks_completed: result.tasks_completed,
+            tasks_failed: result.tasks_failed,
+            failed_tasks,
+        });
+
+        Ok(())
     }
 
     /// List all runs
@@ -64,6 +113,233 @@ impl DurableStore {
             DurableStore::Postgres(p) => p.get_run(run_id).await,
         }
     }
+
+    /// Record that a task has started (or been re-claimed) within a run.
+    pub async fn record_task_started(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        epic_id: Option<&str>,
+        worktree: Option<&str>,
+    ) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.record_task_started(run_id, task_id, epic_id, worktree).await,
+            DurableStore::Postgres(p) => p.record_task_started(run_id, task_id, epic_id, worktree).await,
+        }
+    }
+
+    /// Record that a task reached a terminal state.
+    pub async fn record_task_finished(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        state: TaskState,
+        error: Option<&str>,
+    ) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.record_task_finished(run_id, task_id, state, error).await,
+            DurableStore::Postgres(p) => p.record_task_finished(run_id, task_id, state, error).await,
+        }
+    }
+
+    /// Return the task rows for a run that are not yet in a terminal state,
+    /// so a crashed run can be picked back up rather than restarted.
+    pub async fn resume_run(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        match self {
+            DurableStore::Sqlite(s) => s.resume_run(run_id).await,
+            DurableStore::Postgres(p) => p.resume_run(run_id).await,
+        }
+    }
+
+    /// Every task row recorded for a run, in whatever state it's in -
+    /// unlike [`DurableStore::resume_run`], this includes tasks that
+    /// already reached a terminal state before the crash.
+    pub async fn all_task_records(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        match self {
+            DurableStore::Sqlite(s) => s.all_task_records(run_id).await,
+            DurableStore::Postgres(p) => p.all_task_records(run_id).await,
+        }
+    }
+
+    /// Atomically claim the next `ready` task for `worker_id`, turning the
+    /// store into a pull-based queue multiple Drover processes can share.
+    pub async fn claim_next_task(&self, run_id: &Uuid, worker_id: &str) -> Result<Option<TaskRecord>> {
+        match self {
+            DurableStore::Sqlite(s) => s.claim_next_task(run_id, worker_id).await,
+            DurableStore::Postgres(p) => p.claim_next_task(run_id, worker_id).await,
+        }
+    }
+
+    /// Promote a task to `ready` once its dependencies have cleared.
+    pub async fn mark_ready(&self, run_id: &Uuid, task_id: &str) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.mark_ready(run_id, task_id).await,
+            DurableStore::Postgres(p) => p.mark_ready(run_id, task_id).await,
+        }
+    }
+
+    /// Record a task failure and either reschedule it with exponential
+    /// backoff or transition it to the terminal `Error` state if
+    /// `max_attempts` has been exhausted.
+    pub async fn reschedule_task(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        error: &str,
+        max_attempts: u32,
+    ) -> Result<TaskState> {
+        let state = match self {
+            DurableStore::Sqlite(s) => s.reschedule_task(run_id, task_id, error, max_attempts).await,
+            DurableStore::Postgres(p) => p.reschedule_task(run_id, task_id, error, max_attempts).await,
+        }?;
+
+        if state == TaskState::Error {
+            self.notifier().notify_task_failed(*run_id, TaskFailureSummary {
+                task_id: task_id.to_string(),
+                error: error.to_string(),
+            });
+        }
+
+        Ok(state)
+    }
+
+    /// Record that a worker is still alive, along with the tranquility
+    /// multiplier it's currently pacing itself with. Workers call this
+    /// periodically while holding a claimed task.
+    pub async fn heartbeat(&self, run_id: &Uuid, worker_id: &str, tranquility: f64) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.heartbeat(run_id, worker_id, tranquility).await,
+            DurableStore::Postgres(p) => p.heartbeat(run_id, worker_id, tranquility).await,
+        }
+    }
+
+    /// Return claimed tasks whose worker has gone quiet for longer than
+    /// `timeout` (or never sent a heartbeat at all) to `ready`, and report
+    /// how many were reclaimed.
+    pub async fn reclaim_stale_tasks(&self, run_id: &Uuid, timeout: Duration) -> Result<u64> {
+        let reclaimed = match self {
+            DurableStore::Sqlite(s) => s.reclaim_stale_tasks(run_id, timeout).await,
+            DurableStore::Postgres(p) => p.reclaim_stale_tasks(run_id, timeout).await,
+        }?;
+
+        if reclaimed > 0 {
+            tracing::warn!(run_id = %run_id, reclaimed, "Recovered tasks from crashed worker(s)");
+        }
+
+        Ok(reclaimed)
+    }
+
+    /// Wall-clock duration of every completed run, most recent first.
+    pub async fn run_durations(&self) -> Result<Vec<RunDuration>> {
+        match self {
+            DurableStore::Sqlite(s) => s.run_durations().await,
+            DurableStore::Postgres(p) => p.run_durations().await,
+        }
+    }
+
+    /// Task completion/failure counts and average per-task duration for a
+    /// run, aggregated in SQL over the per-task rows.
+    pub async fn task_throughput(&self, run_id: &Uuid) -> Result<TaskThroughput> {
+        match self {
+            DurableStore::Sqlite(s) => s.task_throughput(run_id).await,
+            DurableStore::Postgres(p) => p.task_throughput(run_id).await,
+        }
+    }
+
+    /// Per-epic total/failed task counts for a run, aggregated in SQL.
+    pub async fn failure_breakdown_by_epic(&self, run_id: &Uuid) -> Result<Vec<EpicFailureBreakdown>> {
+        match self {
+            DurableStore::Sqlite(s) => s.failure_breakdown_by_epic(run_id).await,
+            DurableStore::Postgres(p) => p.failure_breakdown_by_epic(run_id).await,
+        }
+    }
+
+    /// Poll-timer check: return tasks that have been `running` for longer
+    /// than `threshold` - a likely-stuck AI worker - logging a warning for
+    /// each so it shows up in `tracing` even if nothing reads the return
+    /// value.
+    pub async fn poll_timing(&self, run_id: &Uuid, threshold: Duration) -> Result<Vec<StuckTask>> {
+        let stuck = match self {
+            DurableStore::Sqlite(s) => s.poll_timing(run_id, threshold).await,
+            DurableStore::Postgres(p) => p.poll_timing(run_id, threshold).await,
+        }?;
+
+        for task in &stuck {
+            tracing::warn!(
+                run_id = %run_id,
+                task_id = %task.task_id,
+                claimed_by = ?task.claimed_by,
+                running_for = ?task.running_for.to_std().unwrap_or_default(),
+                "Task has been running longer than the stall threshold"
+            );
+        }
+
+        Ok(stuck)
+    }
+
+    /// Every worker that has ever heartbeated on `run_id`, with whatever
+    /// task it currently holds - the cross-process view `drover workers`
+    /// renders, since a heartbeat outlives the process that sent it.
+    pub async fn worker_statuses(&self, run_id: &Uuid) -> Result<Vec<WorkerHeartbeatStatus>> {
+        match self {
+            DurableStore::Sqlite(s) => s.worker_statuses(run_id).await,
+            DurableStore::Postgres(p) => p.worker_statuses(run_id).await,
+        }
+    }
+
+    /// Persist a new recurring schedule, firing immediately on its first
+    /// tick (`next_fire_at` is set to now).
+    pub async fn create_schedule(&self, epic_filter: Option<&str>, interval: Duration, require_ready: bool) -> Result<Uuid> {
+        match self {
+            DurableStore::Sqlite(s) => s.create_schedule(epic_filter, interval, require_ready).await,
+            DurableStore::Postgres(p) => p.create_schedule(epic_filter, interval, require_ready).await,
+        }
+    }
+
+    /// Every schedule, most recently created first.
+    pub async fn list_schedules(&self) -> Result<Vec<ScheduleEntry>> {
+        match self {
+            DurableStore::Sqlite(s) => s.list_schedules().await,
+            DurableStore::Postgres(p) => p.list_schedules().await,
+        }
+    }
+
+    /// Schedules whose `next_fire_at` has elapsed and that aren't already
+    /// mid-run.
+    pub async fn due_schedules(&self, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<ScheduleEntry>> {
+        match self {
+            DurableStore::Sqlite(s) => s.due_schedules(now).await,
+            DurableStore::Postgres(p) => p.due_schedules(now).await,
+        }
+    }
+
+    /// Atomically mark a schedule active, so a concurrent tick (or another
+    /// process sharing this store) can't launch a second overlapping run for
+    /// the same target. Returns `false` if it was already active.
+    pub async fn try_start_schedule_run(&self, schedule_id: &Uuid) -> Result<bool> {
+        match self {
+            DurableStore::Sqlite(s) => s.try_start_schedule_run(schedule_id).await,
+            DurableStore::Postgres(p) => p.try_start_schedule_run(schedule_id).await,
+        }
+    }
+
+    /// Release a schedule's active claim and push `next_fire_at` out by its
+    /// interval from now.
+    pub async fn finish_schedule_run(&self, schedule_id: &Uuid, interval: Duration) -> Result<()> {
+        match self {
+            DurableStore::Sqlite(s) => s.finish_schedule_run(schedule_id, interval).await,
+            DurableStore::Postgres(p) => p.finish_schedule_run(schedule_id, interval).await,
+        }
+    }
+
+    /// The earliest `next_fire_at` across every schedule, for surfacing in
+    /// [`crate::drover::ProjectStatus`].
+    pub async fn next_scheduled_fire(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        match self {
+            DurableStore::Sqlite(s) => s.next_scheduled_fire().await,
+            DurableStore::Postgres(p) => p.next_scheduled_fire().await,
+        }
+    }
 }
 
 /// Record of a Drover run
@@ -78,18 +354,238 @@ pub struct RunRecord {
     pub tasks_failed: i32,
 }
 
+/// Lifecycle of a single task's row in the `tasks` table.
+///
+/// `Pending -> Running -> {Finished, Error}`. The dashboard and the resume
+/// logic both key off this enum so "in progress" means one thing everywhere.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    Pending,
+    Ready,
+    Running,
+    Finished,
+    Error,
+}
+
+impl TaskState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TaskState::Pending => "pending",
+            TaskState::Ready => "ready",
+            TaskState::Running => "running",
+            TaskState::Finished => "finished",
+            TaskState::Error => "error",
+        }
+    }
+
+    fn from_str(s: &str) -> Self {
+        match s {
+            "ready" => TaskState::Ready,
+            "running" => TaskState::Running,
+            "finished" => TaskState::Finished,
+            "error" => TaskState::Error,
+            _ => TaskState::Pending,
+        }
+    }
+}
+
+/// A durable record of one task's progress within a run.
+#[derive(Debug, Clone)]
+pub struct TaskRecord {
+    pub run_id: Uuid,
+    pub task_id: String,
+    pub epic_id: Option<String>,
+    pub state: TaskState,
+    pub attempt: i32,
+    pub priority: i32,
+    pub worktree: Option<String>,
+    pub claimed_by: Option<String>,
+    pub claimed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub next_retry_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub error: Option<String>,
+}
+
+/// Wall-clock duration of one completed run.
+#[derive(Debug, Clone)]
+pub struct RunDuration {
+    pub run_id: Uuid,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+    pub duration: chrono::Duration,
+}
+
+/// Aggregate task throughput for a single run.
+#[derive(Debug, Clone)]
+pub struct TaskThroughput {
+    pub tasks_completed: i64,
+    pub tasks_failed: i64,
+    pub avg_task_duration: Option<chrono::Duration>,
+}
+
+/// Total/failed task counts for one epic within a run.
+#[derive(Debug, Clone)]
+pub struct EpicFailureBreakdown {
+    pub epic_id: Option<String>,
+    pub total: i64,
+    pub failed: i64,
+}
+
+/// A worker's last known heartbeat and the task it currently holds, if any.
+#[derive(Debug, Clone)]
+pub struct WorkerHeartbeatStatus {
+    pub worker_id: String,
+    pub last_seen: chrono::DateTime<chrono::Utc>,
+    pub current_task: Option<String>,
+    pub task_started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The tranquility multiplier reported with the worker's last heartbeat.
+    pub tranquility: f64,
+}
+
+/// A task that has been `running` for longer than the configured stall
+/// threshold - surfaced by [`DurableStore::poll_timing`].
+#[derive(Debug, Clone)]
+pub struct StuckTask {
+    pub task_id: String,
+    pub epic_id: Option<String>,
+    pub claimed_by: Option<String>,
+    pub running_for: chrono::Duration,
+}
+
+/// A recurring job that periodically re-discovers work for a target (the
+/// whole project, or a specific `epic_filter`) and launches a `Drover` run
+/// when it's due - see [`crate::scheduler::Scheduler`].
+#[derive(Debug, Clone)]
+pub struct ScheduleEntry {
+    pub id: Uuid,
+    pub epic_filter: Option<String>,
+    pub interval: Duration,
+    /// Skip firing if `discover_work` finds no `Ready` tasks for this target.
+    pub require_ready: bool,
+    /// Whether a run launched by this schedule is currently in flight -
+    /// while `true`, due ticks are skipped so runs never overlap.
+    pub active: bool,
+    pub last_run_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub next_fire_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Exponential backoff used when rescheduling a failed task: `base * 2^(attempt-1)`,
+/// capped so a flaky task never waits longer than an hour between attempts.
+const RETRY_BASE: std::time::Duration = std::time::Duration::from_secs(10);
+const RETRY_CAP: std::time::Duration = std::time::Duration::from_secs(3600);
+
+fn backoff_delay(attempt: i32) -> chrono::Duration {
+    let exponent = attempt.saturating_sub(1).clamp(0, 10) as u32;
+    let delay = RETRY_BASE.saturating_mul(1u32 << exponent).min(RETRY_CAP);
+    chrono::Duration::from_std(delay).unwrap_or_else(|_| chrono::Duration::seconds(RETRY_CAP.as_secs() as i64))
+}
+
+const TASKS_SCHEMA_SQLITE: &str = r#"
+CREATE TABLE IF NOT EXISTS tasks (
+    run_id TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    epic_id TEXT,
+    state TEXT NOT NULL,
+    attempt INTEGER NOT NULL DEFAULT 0,
+    priority INTEGER NOT NULL DEFAULT 0,
+    worktree TEXT,
+    claimed_by TEXT,
+    claimed_at TEXT,
+    created_at TEXT NOT NULL,
+    started_at TEXT,
+    finished_at TEXT,
+    next_retry_at TEXT,
+    error TEXT,
+    PRIMARY KEY (run_id, task_id)
+)
+"#;
+
+const HEARTBEATS_SCHEMA_SQLITE: &str = r#"
+CREATE TABLE IF NOT EXISTS worker_heartbeats (
+    worker_id TEXT PRIMARY KEY,
+    run_id TEXT NOT NULL,
+    last_seen TEXT NOT NULL,
+    tranquility REAL NOT NULL DEFAULT 0.0
+)
+"#;
+
+const HEARTBEATS_INDEX_SQLITE: &str =
+    "CREATE INDEX IF NOT EXISTS idx_worker_heartbeats_last_seen ON worker_heartbeats (last_seen)";
+
+const SCHEDULES_SCHEMA_SQLITE: &str = r#"
+CREATE TABLE IF NOT EXISTS schedules (
+    id TEXT PRIMARY KEY,
+    epic_filter TEXT,
+    interval_secs INTEGER NOT NULL,
+    require_ready INTEGER NOT NULL DEFAULT 1,
+    active INTEGER NOT NULL DEFAULT 0,
+    last_run_at TEXT,
+    next_fire_at TEXT NOT NULL,
+    created_at TEXT NOT NULL
+)
+"#;
+
+const TASKS_SCHEMA_POSTGRES: &str = r#"
+CREATE TABLE IF NOT EXISTS tasks (
+    run_id UUID NOT NULL,
+    task_id TEXT NOT NULL,
+    epic_id TEXT,
+    state TEXT NOT NULL,
+    attempt INTEGER NOT NULL DEFAULT 0,
+    priority INTEGER NOT NULL DEFAULT 0,
+    worktree TEXT,
+    claimed_by TEXT,
+    claimed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL,
+    started_at TIMESTAMPTZ,
+    finished_at TIMESTAMPTZ,
+    next_retry_at TIMESTAMPTZ,
+    error TEXT,
+    PRIMARY KEY (run_id, task_id)
+)
+"#;
+
+const HEARTBEATS_SCHEMA_POSTGRES: &str = r#"
+CREATE TABLE IF NOT EXISTS worker_heartbeats (
+    worker_id TEXT PRIMARY KEY,
+    run_id UUID NOT NULL,
+    last_seen TIMESTAMPTZ NOT NULL,
+    tranquility DOUBLE PRECISION NOT NULL DEFAULT 0.0
+)
+"#;
+
+const HEARTBEATS_INDEX_POSTGRES: &str =
+    "CREATE INDEX IF NOT EXISTS idx_worker_heartbeats_last_seen ON worker_heartbeats (last_seen)";
+
+const SCHEDULES_SCHEMA_POSTGRES: &str = r#"
+CREATE TABLE IF NOT EXISTS schedules (
+    id UUID PRIMARY KEY,
+    epic_filter TEXT,
+    interval_secs BIGINT NOT NULL,
+    require_ready BOOLEAN NOT NULL DEFAULT TRUE,
+    active BOOLEAN NOT NULL DEFAULT FALSE,
+    last_run_at TIMESTAMPTZ,
+    next_fire_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+)
+"#;
+
 // ============================================================================
 // SQLITE IMPLEMENTATION
 // ============================================================================
 
+#[derive(Clone)]
 pub struct SqliteStore {
     pool: SqlitePool,
+    notifier: Notifier,
 }
 
 impl SqliteStore {
-    pub async fn new(path: &str) -> Result<Self> {
+    pub async fn new(path: &str, notifier: Notifier) -> Result<Self> {
         let pool = SqlitePool::connect(path).await?;
-        Ok(Self { pool })
+        Ok(Self { pool, notifier })
     }
 
     async fn init(&self) -> Result<()> {
@@ -103,23 +599,30 @@ impl SqliteStore {
                 tasks_total INTEGER NOT NULL,
                 tasks_completed INTEGER NOT NULL,
                 tasks_failed INTEGER NOT NULL,
-                manifest TEXT NOT NULL
+                manifest TEXT NOT NULL,
+                config TEXT NOT NULL DEFAULT '{}'
             )
             "#,
         )
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(TASKS_SCHEMA_SQLITE).execute(&self.pool).await?;
+        sqlx::query(HEARTBEATS_SCHEMA_SQLITE).execute(&self.pool).await?;
+        sqlx::query(HEARTBEATS_INDEX_SQLITE).execute(&self.pool).await?;
+        sqlx::query(SCHEDULES_SCHEMA_SQLITE).execute(&self.pool).await?;
+
         Ok(())
     }
 
-    async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest) -> Result<()> {
+    async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest, config: &DroverConfigSnapshot) -> Result<()> {
         let manifest_json = serde_json::to_string(manifest)?;
+        let config_json = serde_json::to_string(config)?;
 
         sqlx::query(
             r#"
-            INSERT INTO runs (id, started_at, tasks_total, tasks_completed, tasks_failed, manifest)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            INSERT INTO runs (id, started_at, tasks_total, tasks_completed, tasks_failed, manifest, config)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
             "#,
         )
         .bind(run_id.to_string())
@@ -128,14 +631,240 @@ impl SqliteStore {
         .bind(0)
         .bind(0)
         .bind(manifest_json)
+        .bind(config_json)
         .execute(&self.pool)
         .await?;
 
+        self.seed_task_rows(run_id, manifest).await?;
+
         tracing::info!("Started run {}", run_id);
         Ok(())
     }
 
+    async fn load_run_snapshot(&self, run_id: &Uuid) -> Result<Option<(WorkManifest, DroverConfigSnapshot)>> {
+        let row: Option<(String, String)> = sqlx::query_as(
+            "SELECT manifest, config FROM runs WHERE id = ?1",
+        )
+        .bind(run_id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(match row {
+            Some((manifest, config)) => Some((serde_json::from_str(&manifest)?, serde_json::from_str(&config)?)),
+            None => None,
+        })
+    }
+
+    async fn seed_task_rows(&self, run_id: &Uuid, manifest: &WorkManifest) -> Result<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        for epic in &manifest.epics {
+            for task in &epic.tasks {
+                self.seed_task_row(run_id, task, Some(&epic.id), &now).await?;
+            }
+        }
+        for task in &manifest.standalone_tasks {
+            self.seed_task_row(run_id, task, None, &now).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn seed_task_row(
+        &self,
+        run_id: &Uuid,
+        task: &crate::drover::Task,
+        epic_id: Option<&str>,
+        now: &str,
+    ) -> Result<()> {
+        let initial_state = if task.status == crate::drover::TaskStatus::Ready {
+            TaskState::Ready
+        } else {
+            TaskState::Pending
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (run_id, task_id, epic_id, state, attempt, priority, created_at)
+            VALUES (?1, ?2, ?3, ?4, 0, ?5, ?6)
+            ON CONFLICT (run_id, task_id) DO NOTHING
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(&task.id)
+        .bind(epic_id)
+        .bind(initial_state.as_str())
+        .bind(task.priority)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Promote a task to `ready` once its blockers have cleared, so the
+    /// queue's claim query can pick it up.
+    async fn mark_ready(&self, run_id: &Uuid, task_id: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE tasks SET state = 'ready'
+            WHERE run_id = ?1 AND task_id = ?2 AND state NOT IN ('finished', 'error', 'running')
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Atomically claim the next ready task for `worker_id`.
+    ///
+    /// SQLite has no `SKIP LOCKED`, so this emulates it with a
+    /// `BEGIN IMMEDIATE` transaction on a single connection: the write lock
+    /// it takes means a second claimant blocks until this one commits rather
+    /// than racing it, which is sufficient for single-writer SQLite.
+    async fn claim_next_task(&self, run_id: &Uuid, worker_id: &str) -> Result<Option<TaskRecord>> {
+        let mut conn = self.pool.acquire().await?;
+        sqlx::query("BEGIN IMMEDIATE").execute(&mut *conn).await?;
+
+        let row = sqlx::query_as::<_, (String, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at
+            FROM tasks
+            WHERE run_id = ?1 AND state = 'ready' AND (next_retry_at IS NULL OR next_retry_at <= ?2)
+            ORDER BY priority DESC, created_at ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(chrono::Utc::now().to_rfc3339())
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let Some((run_id_s, task_id, epic_id, _state, attempt, priority, worktree, _claimed_by, _claimed_at, created_at, started_at, _finished_at)) = row else {
+            sqlx::query("COMMIT").execute(&mut *conn).await?;
+            return Ok(None);
+        };
+
+        let claimed_at = chrono::Utc::now().to_rfc3339();
+
+        sqlx::query(
+            r#"
+            UPDATE tasks SET state = 'running', claimed_by = ?1, claimed_at = ?2
+            WHERE run_id = ?3 AND task_id = ?4
+            "#,
+        )
+        .bind(worker_id)
+        .bind(&claimed_at)
+        .bind(&run_id_s)
+        .bind(&task_id)
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query("COMMIT").execute(&mut *conn).await?;
+
+        Ok(Some(TaskRecord {
+            run_id: Uuid::parse_str(&run_id_s).unwrap_or_default(),
+            task_id,
+            epic_id,
+            state: TaskState::Running,
+            attempt,
+            priority,
+            worktree,
+            claimed_by: Some(worker_id.to_string()),
+            claimed_at: chrono::DateTime::parse_from_rfc3339(&claimed_at).ok().map(|dt| dt.with_timezone(&chrono::Utc)),
+            created_at: created_at.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc)),
+            started_at: started_at.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc)),
+            finished_at: None,
+            error: None,
+            next_retry_at: None,
+        }))
+    }
+
+    /// Reschedule a failed task with exponential backoff, or transition it
+    /// to the terminal `Error` state once `max_attempts` is exhausted.
+    async fn reschedule_task(&self, run_id: &Uuid, task_id: &str, error: &str, max_attempts: u32) -> Result<TaskState> {
+        let row: Option<(i32,)> = sqlx::query_as(
+            "SELECT attempt FROM tasks WHERE run_id = ?1 AND task_id = ?2",
+        )
+        .bind(run_id.to_string())
+        .bind(task_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let attempt = row.map(|(a,)| a).unwrap_or(0) + 1;
+
+        if attempt >= max_attempts as i32 {
+            self.record_task_finished(run_id, task_id, TaskState::Error, Some(error)).await?;
+            return Ok(TaskState::Error);
+        }
+
+        let next_retry_at = chrono::Utc::now() + backoff_delay(attempt);
+
+        sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = 'ready', attempt = ?1, next_retry_at = ?2, error = ?3, claimed_by = NULL, claimed_at = NULL
+            WHERE run_id = ?4 AND task_id = ?5
+            "#,
+        )
+        .bind(attempt)
+        .bind(next_retry_at.to_rfc3339())
+        .bind(error)
+        .bind(run_id.to_string())
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(TaskState::Ready)
+    }
+
+    async fn heartbeat(&self, run_id: &Uuid, worker_id: &str, tranquility: f64) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO worker_heartbeats (worker_id, run_id, last_seen, tranquility)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT (worker_id) DO UPDATE SET run_id = excluded.run_id, last_seen = excluded.last_seen, tranquility = excluded.tranquility
+            "#,
+        )
+        .bind(worker_id)
+        .bind(run_id.to_string())
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(tranquility)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reclaim_stale_tasks(&self, run_id: &Uuid, timeout: Duration) -> Result<u64> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::from_std(timeout).unwrap_or(chrono::Duration::zero())).to_rfc3339();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = 'ready', claimed_by = NULL, claimed_at = NULL
+            WHERE run_id = ?1
+              AND state = 'running'
+              AND claimed_by IS NOT NULL
+              AND claimed_by NOT IN (
+                  SELECT worker_id FROM worker_heartbeats WHERE run_id = ?1 AND last_seen > ?2
+              )
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(&cutoff)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn complete_run(&self, run_id: &Uuid, result: &DroverResult) -> Result<()> {
+        let (completed, failed) = self.aggregate_task_counts(run_id, result).await?;
+
         sqlx::query(
             r#"
             UPDATE runs
@@ -148,8 +877,8 @@ impl SqliteStore {
         )
         .bind(chrono::Utc::now().to_rfc3339())
         .bind(result.success)
-        .bind(result.tasks_completed as i32)
-        .bind(result.tasks_failed as i32)
+        .bind(completed)
+        .bind(failed)
         .bind(run_id.to_string())
         .execute(&self.pool)
         .await?;
@@ -158,6 +887,29 @@ impl SqliteStore {
         Ok(())
     }
 
+    async fn aggregate_task_counts(&self, run_id: &Uuid, result: &DroverResult) -> Result<(i32, i32)> {
+        let row: (i64, i64) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE state = 'finished'),
+                COUNT(*) FILTER (WHERE state = 'error')
+            FROM tasks
+            WHERE run_id = ?1
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+
+        if row.0 == 0 && row.1 == 0 {
+            // No per-task rows recorded for this run (e.g. a legacy run) - fall
+            // back to the caller's in-memory tally.
+            Ok((result.tasks_completed as i32, result.tasks_failed as i32))
+        } else {
+            Ok((row.0 as i32, row.1 as i32))
+        }
+    }
+
     async fn list_runs(&self) -> Result<Vec<RunRecord>> {
         let rows = sqlx::query_as::<_, (String, String, Option<String>, Option<bool>, i32, i32, i32)>(
             "SELECT id, started_at, completed_at, success, tasks_total, tasks_completed, tasks_failed FROM runs ORDER BY started_at DESC"
@@ -206,20 +958,353 @@ impl SqliteStore {
             }
         }))
     }
-}
+
+    async fn record_task_started(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        epic_id: Option<&str>,
+        worktree: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (run_id, task_id, epic_id, state, attempt, worktree, started_at, created_at)
+            VALUES (?1, ?2, ?3, 'running', 1, ?4, ?5, ?5)
+            ON CONFLICT (run_id, task_id) DO UPDATE SET
+                state = 'running',
+                attempt = tasks.attempt + 1,
+                worktree = excluded.worktree,
+                started_at = excluded.started_at,
+                finished_at = NULL,
+                error = NULL
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(task_id)
+        .bind(epic_id)
+        .bind(worktree)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_task_finished(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        state: TaskState,
+        error: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = ?1, finished_at = ?2, error = ?3
+            WHERE run_id = ?4 AND task_id = ?5
+            "#,
+        )
+        .bind(state.as_str())
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(error)
+        .bind(run_id.to_string())
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn resume_run(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (String, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error
+            FROM tasks
+            WHERE run_id = ?1 AND state NOT IN ('finished', 'error')
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error)| {
+            let parse = |s: Option<String>| s.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc));
+            TaskRecord {
+                run_id: Uuid::parse_str(&run_id).unwrap_or_default(),
+                task_id,
+                epic_id,
+                state: TaskState::from_str(&state),
+                attempt,
+                priority,
+                worktree,
+                claimed_by,
+                claimed_at: parse(claimed_at),
+                created_at: parse(created_at),
+                started_at: parse(started_at),
+                finished_at: parse(finished_at),
+                next_retry_at: parse(next_retry_at),
+                error,
+            }
+        }).collect())
+    }
+
+    async fn all_task_records(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (String, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error
+            FROM tasks
+            WHERE run_id = ?1
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error)| {
+            let parse = |s: Option<String>| s.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc));
+            TaskRecord {
+                run_id: Uuid::parse_str(&run_id).unwrap_or_default(),
+                task_id,
+                epic_id,
+                state: TaskState::from_str(&state),
+                attempt,
+                priority,
+                worktree,
+                claimed_by,
+                claimed_at: parse(claimed_at),
+                created_at: parse(created_at),
+                started_at: parse(started_at),
+                finished_at: parse(finished_at),
+                next_retry_at: parse(next_retry_at),
+                error,
+            }
+        }).collect())
+    }
+
+    async fn run_durations(&self) -> Result<Vec<RunDuration>> {
+        let rows = sqlx::query_as::<_, (String, String, String)>(
+            "SELECT id, started_at, completed_at FROM runs WHERE completed_at IS NOT NULL ORDER BY started_at DESC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().filter_map(|(id, started, completed)| {
+            let started_at = chrono::DateTime::parse_from_rfc3339(&started).ok()?.with_timezone(&chrono::Utc);
+            let completed_at = chrono::DateTime::parse_from_rfc3339(&completed).ok()?.with_timezone(&chrono::Utc);
+            Some(RunDuration {
+                run_id: Uuid::parse_str(&id).unwrap_or_default(),
+                started_at,
+                completed_at,
+                duration: completed_at - started_at,
+            })
+        }).collect())
+    }
+
+    async fn task_throughput(&self, run_id: &Uuid) -> Result<TaskThroughput> {
+        let row: (i64, i64, Option<f64>) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE state = 'finished'),
+                COUNT(*) FILTER (WHERE state = 'error'),
+                AVG((julianday(finished_at) - julianday(started_at)) * 86400.0)
+                    FILTER (WHERE state = 'finished' AND started_at IS NOT NULL AND finished_at IS NOT NULL)
+            FROM tasks
+            WHERE run_id = ?1
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(TaskThroughput {
+            tasks_completed: row.0,
+            tasks_failed: row.1,
+            avg_task_duration: row.2.map(|secs| chrono::Duration::milliseconds((secs * 1000.0) as i64)),
+        })
+    }
+
+    async fn failure_breakdown_by_epic(&self, run_id: &Uuid) -> Result<Vec<EpicFailureBreakdown>> {
+        let rows = sqlx::query_as::<_, (Option<String>, i64, i64)>(
+            r#"
+            SELECT epic_id, COUNT(*), COUNT(*) FILTER (WHERE state = 'error')
+            FROM tasks
+            WHERE run_id = ?1
+            GROUP BY epic_id
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(epic_id, total, failed)| EpicFailureBreakdown { epic_id, total, failed }).collect())
+    }
+
+    async fn poll_timing(&self, run_id: &Uuid, threshold: Duration) -> Result<Vec<StuckTask>> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::from_std(threshold).unwrap_or(chrono::Duration::zero())).to_rfc3339();
+
+        let rows = sqlx::query_as::<_, (String, Option<String>, Option<String>, String)>(
+            r#"
+            SELECT task_id, epic_id, claimed_by, claimed_at
+            FROM tasks
+            WHERE run_id = ?1 AND state = 'running' AND claimed_at IS NOT NULL AND claimed_at <= ?2
+            ORDER BY claimed_at ASC
+            "#,
+        )
+        .bind(run_id.to_string())
+        .bind(&cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let now = chrono::Utc::now();
+        Ok(rows.into_iter().filter_map(|(task_id, epic_id, claimed_by, claimed_at)| {
+            let claimed_at = chrono::DateTime::parse_from_rfc3339(&claimed_at).ok()?.with_timezone(&chrono::Utc);
+            Some(StuckTask { task_id, epic_id, claimed_by, running_for: now - claimed_at })
+        }).collect())
+    }
+
+    async fn worker_statuses(&self, run_id: &Uuid) -> Result<Vec<WorkerHeartbeatStatus>> {
+        let rows = sqlx::query_as::<_, (String, String, Option<String>, Option<String>, f64)>(
+            r#"
+            SELECT wh.worker_id, wh.last_seen, t.task_id, t.started_at, wh.tranquility
+            FROM worker_heartbeats wh
+            LEFT JOIN tasks t ON t.run_id = wh.run_id AND t.claimed_by = wh.worker_id AND t.state = 'running'
+            WHERE wh.run_id = ?1
+            ORDER BY wh.worker_id
+            "#,
+        )
+        .bind(run_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().filter_map(|(worker_id, last_seen, current_task, started_at, tranquility)| {
+            let last_seen = chrono::DateTime::parse_from_rfc3339(&last_seen).ok()?.with_timezone(&chrono::Utc);
+            let task_started_at = started_at.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc));
+            Some(WorkerHeartbeatStatus { worker_id, last_seen, current_task, task_started_at, tranquility })
+        }).collect())
+    }
+
+    async fn create_schedule(&self, epic_filter: Option<&str>, interval: Duration, require_ready: bool) -> Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = chrono::Utc::now().to_rfc3339();
+
+        sqlx::query(
+            r#"
+            INSERT INTO schedules (id, epic_filter, interval_secs, require_ready, active, next_fire_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, 0, ?5, ?5)
+            "#,
+        )
+        .bind(id.to_string())
+        .bind(epic_filter)
+        .bind(interval.as_secs() as i64)
+        .bind(require_ready)
+        .bind(&now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    async fn list_schedules(&self) -> Result<Vec<ScheduleEntry>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (String, Option<String>, i64, bool, bool, Option<String>, String)>(
+            r#"
+            SELECT id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at
+            FROM schedules
+            ORDER BY created_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().filter_map(|row| schedule_entry_from_row_sqlite(row)).collect())
+    }
+
+    async fn due_schedules(&self, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<ScheduleEntry>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (String, Option<String>, i64, bool, bool, Option<String>, String)>(
+            r#"
+            SELECT id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at
+            FROM schedules
+            WHERE active = 0 AND next_fire_at <= ?1
+            ORDER BY next_fire_at ASC
+            "#,
+        )
+        .bind(now.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().filter_map(|row| schedule_entry_from_row_sqlite(row)).collect())
+    }
+
+    /// `active = 0` in the `WHERE` clause means a second caller racing this
+    /// one affects zero rows rather than stomping the first claim.
+    async fn try_start_schedule_run(&self, schedule_id: &Uuid) -> Result<bool> {
+        let result = sqlx::query("UPDATE schedules SET active = 1 WHERE id = ?1 AND active = 0")
+            .bind(schedule_id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn finish_schedule_run(&self, schedule_id: &Uuid, interval: Duration) -> Result<()> {
+        let now = chrono::Utc::now();
+        let next_fire_at = now + chrono::Duration::from_std(interval).unwrap_or_else(|_| chrono::Duration::zero());
+
+        sqlx::query("UPDATE schedules SET active = 0, last_run_at = ?1, next_fire_at = ?2 WHERE id = ?3")
+            .bind(now.to_rfc3339())
+            .bind(next_fire_at.to_rfc3339())
+            .bind(schedule_id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn next_scheduled_fire(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT next_fire_at FROM schedules ORDER BY next_fire_at ASC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|(s,)| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc)))
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn schedule_entry_from_row_sqlite(
+    row: (String, Option<String>, i64, bool, bool, Option<String>, String),
+) -> Option<ScheduleEntry> {
+    let (id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at) = row;
+
+    Some(ScheduleEntry {
+        id: Uuid::parse_str(&id).ok()?,
+        epic_filter,
+        interval: Duration::from_secs(interval_secs.max(0) as u64),
+        require_ready,
+        active,
+        last_run_at: last_run_at.and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok()).map(|dt| dt.with_timezone(&chrono::Utc)),
+        next_fire_at: chrono::DateTime::parse_from_rfc3339(&next_fire_at).ok()?.with_timezone(&chrono::Utc),
+    })
+}
 
 // ============================================================================
 // POSTGRES IMPLEMENTATION
 // ============================================================================
 
+#[derive(Clone)]
 pub struct PostgresStore {
     pool: PgPool,
+    notifier: Notifier,
 }
 
 impl PostgresStore {
-    pub async fn new(url: &str) -> Result<Self> {
+    pub async fn new(url: &str, notifier: Notifier) -> Result<Self> {
         let pool = PgPool::connect(url).await?;
-        Ok(Self { pool })
+        Ok(Self { pool, notifier })
     }
 
     async fn init(&self) -> Result<()> {
@@ -233,23 +1318,30 @@ impl PostgresStore {
                 tasks_total INTEGER NOT NULL,
                 tasks_completed INTEGER NOT NULL,
                 tasks_failed INTEGER NOT NULL,
-                manifest JSONB NOT NULL
+                manifest JSONB NOT NULL,
+                config JSONB NOT NULL DEFAULT '{}'
             )
             "#,
         )
         .execute(&self.pool)
         .await?;
 
+        sqlx::query(TASKS_SCHEMA_POSTGRES).execute(&self.pool).await?;
+        sqlx::query(HEARTBEATS_SCHEMA_POSTGRES).execute(&self.pool).await?;
+        sqlx::query(HEARTBEATS_INDEX_POSTGRES).execute(&self.pool).await?;
+        sqlx::query(SCHEDULES_SCHEMA_POSTGRES).execute(&self.pool).await?;
+
         Ok(())
     }
 
-    async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest) -> Result<()> {
+    async fn start_run(&self, run_id: &Uuid, manifest: &WorkManifest, config: &DroverConfigSnapshot) -> Result<()> {
         let manifest_json = serde_json::to_value(manifest)?;
+        let config_json = serde_json::to_value(config)?;
 
         sqlx::query(
             r#"
-            INSERT INTO runs (id, started_at, tasks_total, tasks_completed, tasks_failed, manifest)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO runs (id, started_at, tasks_total, tasks_completed, tasks_failed, manifest, config)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
         )
         .bind(run_id)
@@ -258,14 +1350,236 @@ impl PostgresStore {
         .bind(0)
         .bind(0)
         .bind(manifest_json)
+        .bind(config_json)
         .execute(&self.pool)
         .await?;
 
+        self.seed_task_rows(run_id, manifest).await?;
+
         tracing::info!("Started run {}", run_id);
         Ok(())
     }
 
+    async fn load_run_snapshot(&self, run_id: &Uuid) -> Result<Option<(WorkManifest, DroverConfigSnapshot)>> {
+        let row: Option<(serde_json::Value, serde_json::Value)> = sqlx::query_as(
+            "SELECT manifest, config FROM runs WHERE id = $1",
+        )
+        .bind(run_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(match row {
+            Some((manifest, config)) => Some((serde_json::from_value(manifest)?, serde_json::from_value(config)?)),
+            None => None,
+        })
+    }
+
+    async fn seed_task_rows(&self, run_id: &Uuid, manifest: &WorkManifest) -> Result<()> {
+        let now = chrono::Utc::now();
+
+        for epic in &manifest.epics {
+            for task in &epic.tasks {
+                self.seed_task_row(run_id, task, Some(&epic.id), now).await?;
+            }
+        }
+        for task in &manifest.standalone_tasks {
+            self.seed_task_row(run_id, task, None, now).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn seed_task_row(
+        &self,
+        run_id: &Uuid,
+        task: &crate::drover::Task,
+        epic_id: Option<&str>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        let initial_state = if task.status == crate::drover::TaskStatus::Ready {
+            TaskState::Ready
+        } else {
+            TaskState::Pending
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (run_id, task_id, epic_id, state, attempt, priority, created_at)
+            VALUES ($1, $2, $3, $4, 0, $5, $6)
+            ON CONFLICT (run_id, task_id) DO NOTHING
+            "#,
+        )
+        .bind(run_id)
+        .bind(&task.id)
+        .bind(epic_id)
+        .bind(initial_state.as_str())
+        .bind(task.priority)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Promote a task to `ready` once its blockers have cleared, so the
+    /// queue's claim query can pick it up.
+    async fn mark_ready(&self, run_id: &Uuid, task_id: &str) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE tasks SET state = 'ready'
+            WHERE run_id = $1 AND task_id = $2 AND state NOT IN ('finished', 'error', 'running')
+            "#,
+        )
+        .bind(run_id)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Atomically claim the next ready task for `worker_id` using
+    /// `FOR UPDATE SKIP LOCKED`, so concurrent Drover processes sharing this
+    /// Postgres database never grab the same row.
+    async fn claim_next_task(&self, run_id: &Uuid, worker_id: &str) -> Result<Option<TaskRecord>> {
+        let mut tx = self.pool.begin().await?;
+
+        let row = sqlx::query_as::<_, (Uuid, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, created_at, started_at, finished_at
+            FROM tasks
+            WHERE run_id = $1 AND state = 'ready' AND (next_retry_at IS NULL OR next_retry_at <= now())
+            ORDER BY priority DESC, created_at ASC
+            FOR UPDATE SKIP LOCKED
+            LIMIT 1
+            "#,
+        )
+        .bind(run_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some((run_id, task_id, epic_id, _state, attempt, priority, worktree, _claimed_by, created_at, started_at, _finished_at)) = row else {
+            tx.commit().await?;
+            return Ok(None);
+        };
+
+        let claimed_at = chrono::Utc::now();
+
+        sqlx::query(
+            r#"
+            UPDATE tasks SET state = 'running', claimed_by = $1, claimed_at = $2
+            WHERE run_id = $3 AND task_id = $4
+            "#,
+        )
+        .bind(worker_id)
+        .bind(claimed_at)
+        .bind(run_id)
+        .bind(&task_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(TaskRecord {
+            run_id,
+            task_id,
+            epic_id,
+            state: TaskState::Running,
+            attempt,
+            priority,
+            worktree,
+            claimed_by: Some(worker_id.to_string()),
+            claimed_at: Some(claimed_at),
+            created_at,
+            started_at,
+            finished_at: None,
+            next_retry_at: None,
+            error: None,
+        }))
+    }
+
+    /// Reschedule a failed task with exponential backoff, or transition it
+    /// to the terminal `Error` state once `max_attempts` is exhausted.
+    async fn reschedule_task(&self, run_id: &Uuid, task_id: &str, error: &str, max_attempts: u32) -> Result<TaskState> {
+        let row: Option<(i32,)> = sqlx::query_as(
+            "SELECT attempt FROM tasks WHERE run_id = $1 AND task_id = $2",
+        )
+        .bind(run_id)
+        .bind(task_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let attempt = row.map(|(a,)| a).unwrap_or(0) + 1;
+
+        if attempt >= max_attempts as i32 {
+            self.record_task_finished(run_id, task_id, TaskState::Error, Some(error)).await?;
+            return Ok(TaskState::Error);
+        }
+
+        let next_retry_at = chrono::Utc::now() + backoff_delay(attempt);
+
+        sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = 'ready', attempt = $1, next_retry_at = $2, error = $3, claimed_by = NULL, claimed_at = NULL
+            WHERE run_id = $4 AND task_id = $5
+            "#,
+        )
+        .bind(attempt)
+        .bind(next_retry_at)
+        .bind(error)
+        .bind(run_id)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(TaskState::Ready)
+    }
+
+    async fn heartbeat(&self, run_id: &Uuid, worker_id: &str, tranquility: f64) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO worker_heartbeats (worker_id, run_id, last_seen, tranquility)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (worker_id) DO UPDATE SET run_id = excluded.run_id, last_seen = excluded.last_seen, tranquility = excluded.tranquility
+            "#,
+        )
+        .bind(worker_id)
+        .bind(run_id)
+        .bind(chrono::Utc::now())
+        .bind(tranquility)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn reclaim_stale_tasks(&self, run_id: &Uuid, timeout: Duration) -> Result<u64> {
+        let cutoff = chrono::Utc::now() - chrono::Duration::from_std(timeout).unwrap_or(chrono::Duration::zero());
+
+        let result = sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = 'ready', claimed_by = NULL, claimed_at = NULL
+            WHERE run_id = $1
+              AND state = 'running'
+              AND claimed_by IS NOT NULL
+              AND claimed_by NOT IN (
+                  SELECT worker_id FROM worker_heartbeats WHERE run_id = $1 AND last_seen > $2
+              )
+            "#,
+        )
+        .bind(run_id)
+        .bind(cutoff)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn complete_run(&self, run_id: &Uuid, result: &DroverResult) -> Result<()> {
+        let (completed, failed) = self.aggregate_task_counts(run_id, result).await?;
+
         sqlx::query(
             r#"
             UPDATE runs
@@ -278,8 +1592,8 @@ impl PostgresStore {
         )
         .bind(chrono::Utc::now())
         .bind(result.success)
-        .bind(result.tasks_completed as i32)
-        .bind(result.tasks_failed as i32)
+        .bind(completed)
+        .bind(failed)
         .bind(run_id)
         .execute(&self.pool)
         .await?;
@@ -288,6 +1602,27 @@ impl PostgresStore {
         Ok(())
     }
 
+    async fn aggregate_task_counts(&self, run_id: &Uuid, result: &DroverResult) -> Result<(i32, i32)> {
+        let row: (i64, i64) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE state = 'finished'),
+                COUNT(*) FILTER (WHERE state = 'error')
+            FROM tasks
+            WHERE run_id = $1
+            "#,
+        )
+        .bind(run_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        if row.0 == 0 && row.1 == 0 {
+            Ok((result.tasks_completed as i32, result.tasks_failed as i32))
+        } else {
+            Ok((row.0 as i32, row.1 as i32))
+        }
+    }
+
     async fn list_runs(&self) -> Result<Vec<RunRecord>> {
         let rows = sqlx::query_as::<_, (Uuid, chrono::DateTime<chrono::Utc>, Option<chrono::DateTime<chrono::Utc>>, Option<bool>, i32, i32, i32)>(
             "SELECT id, started_at, completed_at, success, tasks_total, tasks_completed, tasks_failed FROM runs ORDER BY started_at DESC"
@@ -328,4 +1663,334 @@ impl PostgresStore {
             }
         }))
     }
+
+    async fn record_task_started(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        epic_id: Option<&str>,
+        worktree: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO tasks (run_id, task_id, epic_id, state, attempt, worktree, started_at, created_at)
+            VALUES ($1, $2, $3, 'running', 1, $4, $5, $5)
+            ON CONFLICT (run_id, task_id) DO UPDATE SET
+                state = 'running',
+                attempt = tasks.attempt + 1,
+                worktree = excluded.worktree,
+                started_at = excluded.started_at,
+                finished_at = NULL,
+                error = NULL
+            "#,
+        )
+        .bind(run_id)
+        .bind(task_id)
+        .bind(epic_id)
+        .bind(worktree)
+        .bind(chrono::Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_task_finished(
+        &self,
+        run_id: &Uuid,
+        task_id: &str,
+        state: TaskState,
+        error: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE tasks
+            SET state = $1, finished_at = $2, error = $3
+            WHERE run_id = $4 AND task_id = $5
+            "#,
+        )
+        .bind(state.as_str())
+        .bind(chrono::Utc::now())
+        .bind(error)
+        .bind(run_id)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn resume_run(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<String>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error
+            FROM tasks
+            WHERE run_id = $1 AND state NOT IN ('finished', 'error')
+            "#,
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error)| {
+            TaskRecord {
+                run_id,
+                task_id,
+                epic_id,
+                state: TaskState::from_str(&state),
+                attempt,
+                priority,
+                worktree,
+                claimed_by,
+                claimed_at,
+                created_at,
+                started_at,
+                finished_at,
+                next_retry_at,
+                error,
+            }
+        }).collect())
+    }
+
+    async fn all_task_records(&self, run_id: &Uuid) -> Result<Vec<TaskRecord>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, String, Option<String>, String, i32, i32, Option<String>, Option<String>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<chrono::DateTime<chrono::Utc>>, Option<String>)>(
+            r#"
+            SELECT run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error
+            FROM tasks
+            WHERE run_id = $1
+            "#,
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(run_id, task_id, epic_id, state, attempt, priority, worktree, claimed_by, claimed_at, created_at, started_at, finished_at, next_retry_at, error)| {
+            TaskRecord {
+                run_id,
+                task_id,
+                epic_id,
+                state: TaskState::from_str(&state),
+                attempt,
+                priority,
+                worktree,
+                claimed_by,
+                claimed_at,
+                created_at,
+                started_at,
+                finished_at,
+                next_retry_at,
+                error,
+            }
+        }).collect())
+    }
+
+    async fn run_durations(&self) -> Result<Vec<RunDuration>> {
+        let rows = sqlx::query_as::<_, (Uuid, chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)>(
+            "SELECT id, started_at, completed_at FROM runs WHERE completed_at IS NOT NULL ORDER BY started_at DESC"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(run_id, started_at, completed_at)| RunDuration {
+            run_id,
+            started_at,
+            completed_at,
+            duration: completed_at - started_at,
+        }).collect())
+    }
+
+    async fn task_throughput(&self, run_id: &Uuid) -> Result<TaskThroughput> {
+        let row: (i64, i64, Option<f64>) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE state = 'finished'),
+                COUNT(*) FILTER (WHERE state = 'error'),
+                AVG(EXTRACT(EPOCH FROM (finished_at - started_at)))
+                    FILTER (WHERE state = 'finished' AND started_at IS NOT NULL AND finished_at IS NOT NULL)
+            FROM tasks
+            WHERE run_id = $1
+            "#,
+        )
+        .bind(run_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(TaskThroughput {
+            tasks_completed: row.0,
+            tasks_failed: row.1,
+            avg_task_duration: row.2.map(|secs| chrono::Duration::milliseconds((secs * 1000.0) as i64)),
+        })
+    }
+
+    async fn failure_breakdown_by_epic(&self, run_id: &Uuid) -> Result<Vec<EpicFailureBreakdown>> {
+        let rows = sqlx::query_as::<_, (Option<String>, i64, i64)>(
+            r#"
+            SELECT epic_id, COUNT(*), COUNT(*) FILTER (WHERE state = 'error')
+            FROM tasks
+            WHERE run_id = $1
+            GROUP BY epic_id
+            "#,
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(epic_id, total, failed)| EpicFailureBreakdown { epic_id, total, failed }).collect())
+    }
+
+    async fn poll_timing(&self, run_id: &Uuid, threshold: Duration) -> Result<Vec<StuckTask>> {
+        let cutoff = chrono::Utc::now() - chrono::Duration::from_std(threshold).unwrap_or(chrono::Duration::zero());
+
+        let rows = sqlx::query_as::<_, (String, Option<String>, Option<String>, chrono::DateTime<chrono::Utc>)>(
+            r#"
+            SELECT task_id, epic_id, claimed_by, claimed_at
+            FROM tasks
+            WHERE run_id = $1 AND state = 'running' AND claimed_at IS NOT NULL AND claimed_at <= $2
+            ORDER BY claimed_at ASC
+            "#,
+        )
+        .bind(run_id)
+        .bind(cutoff)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let now = chrono::Utc::now();
+        Ok(rows.into_iter().map(|(task_id, epic_id, claimed_by, claimed_at)| StuckTask {
+            task_id,
+            epic_id,
+            claimed_by,
+            running_for: now - claimed_at,
+        }).collect())
+    }
+
+    async fn worker_statuses(&self, run_id: &Uuid) -> Result<Vec<WorkerHeartbeatStatus>> {
+        let rows = sqlx::query_as::<_, (String, chrono::DateTime<chrono::Utc>, Option<String>, Option<chrono::DateTime<chrono::Utc>>, f64)>(
+            r#"
+            SELECT wh.worker_id, wh.last_seen, t.task_id, t.started_at, wh.tranquility
+            FROM worker_heartbeats wh
+            LEFT JOIN tasks t ON t.run_id = wh.run_id AND t.claimed_by = wh.worker_id AND t.state = 'running'
+            WHERE wh.run_id = $1
+            ORDER BY wh.worker_id
+            "#,
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(worker_id, last_seen, current_task, task_started_at, tranquility)| WorkerHeartbeatStatus {
+            worker_id,
+            last_seen,
+            current_task,
+            task_started_at,
+            tranquility,
+        }).collect())
+    }
+
+    async fn create_schedule(&self, epic_filter: Option<&str>, interval: Duration, require_ready: bool) -> Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = chrono::Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO schedules (id, epic_filter, interval_secs, require_ready, active, next_fire_at, created_at)
+            VALUES ($1, $2, $3, $4, FALSE, $5, $5)
+            "#,
+        )
+        .bind(id)
+        .bind(epic_filter)
+        .bind(interval.as_secs() as i64)
+        .bind(require_ready)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    async fn list_schedules(&self) -> Result<Vec<ScheduleEntry>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, Option<String>, i64, bool, bool, Option<chrono::DateTime<chrono::Utc>>, chrono::DateTime<chrono::Utc>)>(
+            r#"
+            SELECT id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at
+            FROM schedules
+            ORDER BY created_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(schedule_entry_from_row_postgres).collect())
+    }
+
+    async fn due_schedules(&self, now: chrono::DateTime<chrono::Utc>) -> Result<Vec<ScheduleEntry>> {
+        #[allow(clippy::type_complexity)]
+        let rows = sqlx::query_as::<_, (Uuid, Option<String>, i64, bool, bool, Option<chrono::DateTime<chrono::Utc>>, chrono::DateTime<chrono::Utc>)>(
+            r#"
+            SELECT id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at
+            FROM schedules
+            WHERE active = FALSE AND next_fire_at <= $1
+            ORDER BY next_fire_at ASC
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(schedule_entry_from_row_postgres).collect())
+    }
+
+    /// Relies on `FOR UPDATE`-free optimistic locking via the `active = FALSE`
+    /// guard in the `WHERE` clause - sufficient here since a schedule only
+    /// ever has one writer contending for it at claim time.
+    async fn try_start_schedule_run(&self, schedule_id: &Uuid) -> Result<bool> {
+        let result = sqlx::query("UPDATE schedules SET active = TRUE WHERE id = $1 AND active = FALSE")
+            .bind(schedule_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn finish_schedule_run(&self, schedule_id: &Uuid, interval: Duration) -> Result<()> {
+        let now = chrono::Utc::now();
+        let next_fire_at = now + chrono::Duration::from_std(interval).unwrap_or_else(|_| chrono::Duration::zero());
+
+        sqlx::query("UPDATE schedules SET active = FALSE, last_run_at = $1, next_fire_at = $2 WHERE id = $3")
+            .bind(now)
+            .bind(next_fire_at)
+            .bind(schedule_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn next_scheduled_fire(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let row: Option<(chrono::DateTime<chrono::Utc>,)> = sqlx::query_as(
+            "SELECT next_fire_at FROM schedules ORDER BY next_fire_at ASC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|(t,)| t))
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn schedule_entry_from_row_postgres(
+    row: (Uuid, Option<String>, i64, bool, bool, Option<chrono::DateTime<chrono::Utc>>, chrono::DateTime<chrono::Utc>),
+) -> ScheduleEntry {
+    let (id, epic_filter, interval_secs, require_ready, active, last_run_at, next_fire_at) = row;
+
+    ScheduleEntry {
+        id,
+        epic_filter,
+        interval: Duration::from_secs(interval_secs.max(0) as u64),
+        require_ready,
+        active,
+        last_run_at,
+        next_fire_at,
+    }
 }
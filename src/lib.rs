@@ -5,9 +5,12 @@
 
 pub mod cli;
 pub mod config;
+pub mod control;
 pub mod dashboard;
 pub mod durable;
 pub mod drover;
+pub mod notifier;
+pub mod scheduler;
 pub mod workers;
 
 // Re-export commonly used types
@@ -0,0 +1,135 @@
+//! Outbound notifications for run and task state changes
+//!
+//! Fired from [`crate::durable::DurableStore`] on run completion and on
+//! terminal task failure. Sends are dispatched on a spawned task so a
+//! slow or unreachable receiver never blocks orchestration; failures are
+//! logged, never propagated.
+
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// One configured notification target, loaded from `.drover.toml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NotifierConfig {
+    /// POST a JSON payload to a webhook URL.
+    Webhook { url: String },
+    /// Run a shell command; the payload is passed as JSON on stdin.
+    Shell { command: String },
+}
+
+/// Summary of a completed run, sent as the webhook/shell payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunNotification {
+    pub run_id: uuid::Uuid,
+    pub success: bool,
+    pub tasks_completed: u32,
+    pub tasks_failed: u32,
+    pub failed_tasks: Vec<TaskFailureSummary>,
+}
+
+/// Summary of a single task that exhausted its retries.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskFailureSummary {
+    pub task_id: String,
+    pub error: String,
+}
+
+/// Dispatches outbound notifications for configured targets.
+#[derive(Debug, Clone, Default)]
+pub struct Notifier {
+    targets: Vec<NotifierConfig>,
+}
+
+const WEBHOOK_RETRY_ATTEMPTS: u32 = 3;
+const WEBHOOK_RETRY_BASE: Duration = Duration::from_millis(250);
+
+impl Notifier {
+    pub fn new(targets: Vec<NotifierConfig>) -> Self {
+        Self { targets }
+    }
+
+    /// A notifier with no configured targets - every notify call is a no-op.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Fire a run-completed notification to every configured target.
+    pub fn notify_run_complete(&self, notification: RunNotification) {
+        self.dispatch(serde_json::to_value(&notification).expect("RunNotification serializes"));
+    }
+
+    /// Fire a task-dead-lettered notification (a task that exhausted retries).
+    pub fn notify_task_failed(&self, run_id: uuid::Uuid, failure: TaskFailureSummary) {
+        self.dispatch(serde_json::json!({
+            "run_id": run_id,
+            "event": "task_failed",
+            "task": failure,
+        }));
+    }
+
+    fn dispatch(&self, payload: serde_json::Value) {
+        for target in self.targets.clone() {
+            let payload = payload.clone();
+            tokio::spawn(async move {
+                if let Err(e) = send(&target, &payload).await {
+                    tracing::warn!(?target, error = %e, "Notifier send failed");
+                }
+            });
+        }
+    }
+}
+
+async fn send(target: &NotifierConfig, payload: &serde_json::Value) -> anyhow::Result<()> {
+    match target {
+        NotifierConfig::Webhook { url } => send_webhook(url, payload).await,
+        NotifierConfig::Shell { command } => send_shell(command, payload).await,
+    }
+}
+
+async fn send_webhook(url: &str, payload: &serde_json::Value) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+
+    for attempt in 0..WEBHOOK_RETRY_ATTEMPTS {
+        let response = client.post(url).json(payload).send().await;
+
+        match response {
+            Ok(resp) if resp.status().is_server_error() => {
+                tracing::warn!(url, status = %resp.status(), attempt, "Webhook returned 5xx, retrying");
+            }
+            Ok(resp) if !resp.status().is_success() => {
+                anyhow::bail!("Webhook {} returned {}", url, resp.status());
+            }
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                tracing::warn!(url, error = %e, attempt, "Webhook send failed, retrying");
+            }
+        }
+
+        tokio::time::sleep(WEBHOOK_RETRY_BASE * 2u32.pow(attempt)).await;
+    }
+
+    anyhow::bail!("Webhook {} failed after {} attempts", url, WEBHOOK_RETRY_ATTEMPTS)
+}
+
+async fn send_shell(command: &str, payload: &serde_json::Value) -> anyhow::Result<()> {
+    use std::process::Stdio;
+    use tokio::io::AsyncWriteExt;
+
+    let mut child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(serde_json::to_string(payload)?.as_bytes()).await?;
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        anyhow::bail!("Notifier shell command exited with {}", status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Scheduler subsystem - recurring re-discovery and automatic runs
+//!
+//! A [`Scheduler`] lets Drover act as a daemon instead of a one-shot
+//! invocation: it polls [`DurableStore::due_schedules`] on a fixed tick,
+//! and for every [`ScheduleEntry`] that comes due, re-runs
+//! [`crate::drover::discover_work`] against that entry's target (the
+//! whole project, or a specific `epic_filter`) and launches a `Drover` run
+//! if there's new `Ready` work. `try_start_schedule_run`/
+//! `finish_schedule_run` bracket the fire so a schedule already mid-run
+//! is skipped on the next due check rather than launching a second,
+//! overlapping run for the same target.
+
+use std::path::PathBuf;
+use std::time::Duration;
+use anyhow::Result;
+use tracing::{info, warn};
+
+use crate::config::DroverConfig;
+use crate::drover::{self, Drover};
+use crate::durable::{DurableStore, ScheduleEntry};
+
+/// Polls a project's schedules and fires a `Drover` run for each one that's
+/// due, so the project keeps converging on completion unattended.
+pub struct Scheduler {
+    project_dir: PathBuf,
+    base_config: DroverConfig,
+    store: DurableStore,
+    tick_interval: Duration,
+}
+
+impl Scheduler {
+    pub fn new(project_dir: PathBuf, base_config: DroverConfig, store: DurableStore, tick_interval: Duration) -> Self {
+        Self { project_dir, base_config, store, tick_interval }
+    }
+
+    /// Poll forever, checking for due schedules every `tick_interval`. Runs
+    /// launched by a tick are spawned rather than awaited, so a long-running
+    /// schedule never delays the next tick's check of every other schedule.
+    /// Only returns on an unrecoverable error reading the schedule table.
+    pub async fn run(&self) -> Result<()> {
+        loop {
+            if let Err(e) = self.tick().await {
+                warn!(error = %e, "Scheduler tick failed");
+            }
+            tokio::time::sleep(self.tick_interval).await;
+        }
+    }
+
+    async fn tick(&self) -> Result<()> {
+        let due = self.store.due_schedules(chrono::Utc::now()).await?;
+
+        for schedule in due {
+            if !self.store.try_start_schedule_run(&schedule.id).await? {
+                // Lost the claim to a concurrent tick - already handled.
+                continue;
+            }
+
+            let project_dir = self.project_dir.clone();
+            let base_config = self.base_config.clone();
+            let store = self.store.clone();
+
+            tokio::spawn(async move {
+                if let Err(e) = fire(&project_dir, &base_config, &store, &schedule).await {
+                    warn!(schedule_id = %schedule.id, error = %e, "Scheduled run failed to launch");
+                }
+
+                if let Err(e) = store.finish_schedule_run(&schedule.id, schedule.interval).await {
+                    warn!(schedule_id = %schedule.id, error = %e, "Failed to release schedule claim");
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Re-discover `schedule`'s target and, if it has work worth running, drive
+/// it to completion. A free function (rather than a `Scheduler` method) so
+/// it can be spawned as its own `'static` task per firing schedule.
+async fn fire(project_dir: &PathBuf, base_config: &DroverConfig, store: &DurableStore, schedule: &ScheduleEntry) -> Result<()> {
+    let manifest = drover::discover_work(project_dir, schedule.epic_filter.as_deref()).await?;
+
+    if manifest.total_tasks == 0 || (schedule.require_ready && manifest.ready_tasks == 0) {
+        info!(schedule_id = %schedule.id, "Nothing ready to run - skipping this tick");
+        return Ok(());
+    }
+
+    info!(schedule_id = %schedule.id, target = %manifest.target_description(), "Scheduled run starting");
+
+    let config = base_config.clone().with_project_dir(project_dir.clone());
+    let mut run = Drover::new(manifest, config, store.clone()).await?;
+    let result = run.run().await?;
+
+    info!(
+        schedule_id = %schedule.id,
+        success = result.success,
+        completed = result.tasks_completed,
+        failed = result.tasks_failed,
+        "Scheduled run finished"
+    );
+
+    Ok(())
+}
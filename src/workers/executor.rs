@@ -1,35 +1,70 @@
 //! Task execution using Claude Code
 
 use std::path::PathBuf;
+use std::process::Stdio;
 use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
+use tokio::sync::{mpsc, watch};
 use anyhow::{Result, Context};
 
 use crate::drover::Task;
+use super::tasklog::TaskLogWriter;
+use super::tranquility::{TaskDurationTracker, Tranquilizer};
+use super::{Worker, WorkerActivity, WorkerEvent, WorkerState, WorkerStatus, WorkerTaskOutcome};
+
+/// How often a running task's parsed step-count progress is forwarded as a
+/// [`WorkerEvent::TaskProgress`] - any updates in between are dropped rather
+/// than queued, so a chatty task can't flood the event channel.
+pub const PROGRESS_THROTTLE: Duration = Duration::from_millis(500);
+
+/// Parse a `PROGRESS: <completed>/<total> [message]` line emitted by a task
+/// that reports step counts, e.g. `"PROGRESS: 3/10 running tests"`.
+fn parse_task_progress(line: &str) -> Option<(u64, u64, Option<String>)> {
+    let rest = line.trim().strip_prefix("PROGRESS:")?.trim();
+    let (counts, message) = match rest.split_once(char::is_whitespace) {
+        Some((counts, message)) => (counts, Some(message.trim().to_string()).filter(|m| !m.is_empty())),
+        None => (rest, None),
+    };
+    let (completed, total) = counts.split_once('/')?;
+    Some((completed.parse().ok()?, total.parse().ok()?, message))
+}
 
 /// Executes tasks using Claude Code
 pub struct TaskExecutor {
     project_dir: PathBuf,
     worktree_dir: PathBuf,
+    log_dir: PathBuf,
+    log_max_bytes: u64,
+    log_max_backups: u32,
 }
 
 impl TaskExecutor {
-    pub fn new(project_dir: PathBuf, worktree_dir: PathBuf) -> Self {
+    pub fn new(project_dir: PathBuf, worktree_dir: PathBuf, log_dir: PathBuf, log_max_bytes: u64, log_max_backups: u32) -> Self {
         Self {
             project_dir,
             worktree_dir,
+            log_dir,
+            log_max_bytes,
+            log_max_backups,
         }
     }
 
-    /// Execute a task using Claude Code
-    pub async fn execute(&self, task: &Task) -> Result<Duration> {
+    /// Execute a task using Claude Code, capturing its stdout/stderr to a
+    /// per-task log file as it runs and forwarding each line as a
+    /// [`WorkerEvent::TaskOutput`] for live progress visibility.
+    pub async fn execute(&self, task: &Task, event_tx: &mpsc::Sender<WorkerEvent>) -> Result<Duration> {
         let start = Instant::now();
 
         // Build the prompt from the task
         let prompt = self.build_prompt(task);
 
-        // Execute Claude Code
-        let output = Command::new("claude")
+        let mut log = TaskLogWriter::open(&self.log_dir, &task.id, self.log_max_bytes, self.log_max_backups)?;
+        log.start(&task.id)?;
+
+        // Execute Claude Code, streaming its output into the log as it's
+        // produced rather than buffering it until the process exits.
+        let mut child = Command::new("claude")
             .args([
                 "code",
                 "--prompt",
@@ -37,15 +72,54 @@ impl TaskExecutor {
                 "--non-interactive",
             ])
             .current_dir(&self.project_dir)
-            .output()
-            .await
-            .context("Failed to execute Claude Code")?;
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn Claude Code")?;
+
+        let stdout = child.stdout.take().expect("stdout was piped");
+        let stderr = child.stderr.take().expect("stderr was piped");
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(forward_lines(stdout, tx.clone(), false));
+        tokio::spawn(forward_lines(stderr, tx, true));
+
+        let mut stderr_text = String::new();
+        let mut last_progress_sent: Option<Instant> = None;
+        while let Some((from_stderr, line)) = rx.recv().await {
+            log.write_line(&line)?;
+            let _ = event_tx.send(WorkerEvent::TaskOutput {
+                task_id: task.id.clone(),
+                is_stderr: from_stderr,
+                line: line.clone(),
+            }).await;
+            if from_stderr {
+                stderr_text.push_str(&line);
+                stderr_text.push('\n');
+            }
+
+            if let Some((completed, total, message)) = parse_task_progress(&line) {
+                let now = Instant::now();
+                let due = last_progress_sent.map_or(true, |t| now.duration_since(t) >= PROGRESS_THROTTLE);
+                if due {
+                    last_progress_sent = Some(now);
+                    let _ = event_tx.send(WorkerEvent::TaskProgress {
+                        task_id: task.id.clone(),
+                        completed,
+                        total,
+                        message,
+                    }).await;
+                }
+            }
+        }
 
+        let status = child.wait().await.context("Failed to wait on Claude Code")?;
         let duration = start.elapsed();
+        log.finish(&status.to_string())?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            anyhow::bail!("Claude Code failed: {}", stderr);
+        if !status.success() {
+            anyhow::bail!("Claude Code failed: {}", stderr_text);
         }
 
         tracing::info!("Task {} completed in {:?}", task.id, duration);
@@ -71,3 +145,144 @@ impl TaskExecutor {
         prompt
     }
 }
+
+/// Read `reader` line by line, forwarding each to `tx` tagged with whether
+/// it came from stderr, until the stream closes.
+async fn forward_lines(reader: impl tokio::io::AsyncRead + Unpin, tx: mpsc::UnboundedSender<(bool, String)>, from_stderr: bool) {
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if tx.send((from_stderr, line)).is_err() {
+            break;
+        }
+    }
+}
+
+/// A single worker slot: waits for [`WorkerState`] to push it a task over
+/// its assignment channel and drives it through a [`TaskExecutor`],
+/// reporting its activity for introspection.
+pub struct ClaudeWorker {
+    id: String,
+    state: WorkerState,
+    assignments: mpsc::Receiver<Task>,
+    executor: TaskExecutor,
+    event_tx: mpsc::Sender<WorkerEvent>,
+    status: WorkerStatus,
+    tranquility: watch::Receiver<f64>,
+    /// How long a single task gets before it's treated as hung - see the
+    /// `tokio::time::timeout` wrapping `executor.execute` in `run`.
+    task_timeout: Duration,
+    /// Shared across every worker in the pool - see
+    /// [`tranquility::TaskDurationTracker`].
+    duration_tracker: TaskDurationTracker,
+}
+
+impl ClaudeWorker {
+    pub fn new(
+        id: String,
+        project_dir: PathBuf,
+        worktree_dir: PathBuf,
+        log_dir: PathBuf,
+        log_max_bytes: u64,
+        log_max_backups: u32,
+        task_timeout: Duration,
+        state: WorkerState,
+        assignments: mpsc::Receiver<Task>,
+        event_tx: mpsc::Sender<WorkerEvent>,
+        tranquility: watch::Receiver<f64>,
+        duration_tracker: TaskDurationTracker,
+    ) -> Self {
+        Self {
+            id,
+            state,
+            assignments,
+            executor: TaskExecutor::new(project_dir, worktree_dir, log_dir, log_max_bytes, log_max_backups),
+            event_tx,
+            status: WorkerStatus::default(),
+            tranquility,
+            task_timeout,
+            duration_tracker,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Worker for ClaudeWorker {
+    async fn run(&mut self) -> WorkerActivity {
+        // Parks here until the orchestrator's task-first scheduler
+        // (`WorkerState::assign_ready_tasks`) has something for this slot,
+        // or closes every channel once the run is complete - no polling,
+        // no fixed retry delay.
+        let task = match self.assignments.recv().await {
+            Some(t) => t,
+            None => return WorkerActivity::Done,
+        };
+
+        self.status.progress = Some(format!("running {}", task.id));
+        tracing::info!("Worker {} claimed task {}", self.id, task.id);
+
+        let _ = self.event_tx.send(WorkerEvent::TaskClaimed {
+            task_id: task.id.clone(),
+            epic_id: task.parent_epic.clone(),
+        }).await;
+
+        let tranquilizer = Tranquilizer::start();
+        // A hung Claude Code invocation would otherwise pin this worker
+        // forever - `kill_on_drop` on the spawned process means dropping
+        // the timed-out future actually kills it rather than orphaning it.
+        let result = match tokio::time::timeout(self.task_timeout, self.executor.execute(&task, &self.event_tx)).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!("Task exceeded its {:?} timeout", self.task_timeout)),
+        };
+
+        match result {
+            Ok(duration) => {
+                self.state.release_task(&self.id, WorkerTaskOutcome::Completed).await;
+                let _ = self.event_tx.send(WorkerEvent::TaskCompleted {
+                    task_id: task.id.clone(),
+                    duration,
+                }).await;
+            }
+            Err(e) => {
+                let error_msg = e.to_string();
+                let retriable = !error_msg.contains("blocked");
+
+                if retriable {
+                    self.state.release_task(&self.id, WorkerTaskOutcome::Failed(error_msg.clone())).await;
+                    let _ = self.event_tx.send(WorkerEvent::TaskFailed {
+                        task_id: task.id.clone(),
+                        error: error_msg,
+                        retriable: true,
+                    }).await;
+                } else if error_msg.contains("blocked by") {
+                    self.state.release_task(&self.id, WorkerTaskOutcome::Blocked).await;
+                    let blockers = super::extract_blockers(&error_msg);
+                    let _ = self.event_tx.send(WorkerEvent::TaskBlocked {
+                        task_id: task.id.clone(),
+                        blocked_by: blockers,
+                    }).await;
+                } else {
+                    self.status.persistent_errors.push(error_msg.clone());
+                    self.state.release_task(&self.id, WorkerTaskOutcome::Failed(error_msg.clone())).await;
+                    let _ = self.event_tx.send(WorkerEvent::TaskFailed {
+                        task_id: task.id.clone(),
+                        error: error_msg,
+                        retriable: false,
+                    }).await;
+                }
+            }
+        }
+
+        self.status.progress = None;
+        let tranquility = *self.tranquility.borrow();
+        tranquilizer.tranquilize(tranquility, &self.duration_tracker).await;
+        WorkerActivity::Busy
+    }
+
+    fn name(&self) -> String {
+        self.id.clone()
+    }
+
+    fn status(&self) -> WorkerStatus {
+        self.status.clone()
+    }
+}
@@ -2,36 +2,227 @@
 
 pub mod executor;
 pub mod git;
+pub mod tasklog;
+pub mod tranquility;
 
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::{mpsc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch, RwLock};
 use uuid::Uuid;
 use anyhow::Result;
 use serde::{Serialize, Deserialize};
 
 use crate::drover::{Task, TaskStatus};
-use self::executor::TaskExecutor;
+use crate::durable::DurableStore;
+use crate::control::RunSignal;
+use self::executor::ClaudeWorker;
 
 /// Events emitted by workers
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WorkerEvent {
+    TaskClaimed { task_id: String, epic_id: Option<String> },
+    /// One line of subprocess output captured live as a task runs, tagged by
+    /// stream so stderr can be surfaced distinctly from stdout.
+    TaskOutput { task_id: String, is_stderr: bool, line: String },
     TaskCompleted { task_id: String, duration: Duration },
+    /// A step-count progress update parsed out of a task's own output - see
+    /// `TaskExecutor::execute`'s `PROGRESS: <completed>/<total> [message]`
+    /// line format. Throttled to at most one per
+    /// [`executor::PROGRESS_THROTTLE`] so a chatty task can't flood the
+    /// event channel.
+    TaskProgress { task_id: String, completed: u64, total: u64, message: Option<String> },
     TaskFailed { task_id: String, error: String, retriable: bool },
     TaskBlocked { task_id: String, blocked_by: Vec<String> },
+    /// A task exhausted its retry policy - surfaced distinctly from a single
+    /// [`WorkerEvent::TaskFailed`] so operators can tell a transient failure
+    /// from one that needs a human. Synthesized by [`crate::drover::Drover`]
+    /// once it decides a task's attempts are used up, rather than emitted by
+    /// a worker directly.
+    TaskDeadLettered { task_id: String, attempts: u32, last_error: String },
     Stalled { duration: Duration },
 }
 
+/// What a [`Worker`] was doing the last time its loop turned over.
+///
+/// Named `WorkerActivity` rather than `WorkerState` to avoid colliding with
+/// the pre-existing [`WorkerState`] (the shared claim queue) below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerActivity {
+    Busy,
+    Idle,
+    Done,
+}
+
+/// A worker's current status, for introspection (`drover workers`, the
+/// dashboard).
+#[derive(Debug, Clone, Default)]
+pub struct WorkerStatus {
+    pub progress: Option<String>,
+    pub freeform: Vec<String>,
+    pub persistent_errors: Vec<String>,
+}
+
+impl WorkerStatus {
+    /// The task ID a worker is currently running, parsed back out of the
+    /// `"running <id>"` string `ClaudeWorker::run` sets as `progress`.
+    pub fn current_task_id(&self) -> Option<&str> {
+        self.progress.as_deref()?.strip_prefix("running ")
+    }
+
+    /// The most recent error a worker hit, if any.
+    pub fn last_error(&self) -> Option<&str> {
+        self.persistent_errors.last().map(String::as_str)
+    }
+}
+
+/// One worker slot driving tasks to completion. Implemented by
+/// [`executor::ClaudeWorker`]; lets [`WorkerPool`] introspect each slot
+/// uniformly instead of only seeing an opaque `JoinHandle`.
+#[async_trait::async_trait]
+pub trait Worker: Send {
+    /// Run one turn of the worker's loop - claim a task if idle, drive it to
+    /// completion if busy - and report what it ended up doing.
+    async fn run(&mut self) -> WorkerActivity;
+
+    fn name(&self) -> String;
+
+    fn status(&self) -> WorkerStatus;
+}
+
+/// Tracks the live status of every worker slot in a run, so `drover workers`
+/// and the dashboard can show what each one is doing.
+#[derive(Clone, Default)]
+pub struct WorkerManager {
+    workers: Arc<RwLock<std::collections::HashMap<String, (WorkerActivity, WorkerStatus, Instant)>>>,
+}
+
+/// A single worker's status as of the last report, with how long it has
+/// held that activity.
+#[derive(Debug, Clone)]
+pub struct WorkerSnapshot {
+    pub name: String,
+    pub activity: WorkerActivity,
+    pub status: WorkerStatus,
+    pub since: Duration,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn report(&self, name: &str, activity: WorkerActivity, status: WorkerStatus) {
+        let mut workers = self.workers.write().await;
+        let since = match workers.get(name) {
+            Some((prev_activity, _, since)) if *prev_activity == activity => *since,
+            _ => Instant::now(),
+        };
+        workers.insert(name.to_string(), (activity, status, since));
+    }
+
+    /// A snapshot of every worker's current status, sorted by name.
+    pub async fn snapshot(&self) -> Vec<WorkerSnapshot> {
+        let workers = self.workers.read().await;
+        let mut rows: Vec<WorkerSnapshot> = workers.iter()
+            .map(|(name, (activity, status, since))| WorkerSnapshot {
+                name: name.clone(),
+                activity: *activity,
+                status: status.clone(),
+                since: since.elapsed(),
+            })
+            .collect();
+        rows.sort_by(|a, b| a.name.cmp(&b.name));
+        rows
+    }
+}
+
+/// A worker slot's live status as tracked by [`WorkerState`] - distinct from
+/// [`WorkerActivity`] (what a slot did on its *last* loop turn) in that it
+/// also distinguishes `Blocked` (last task came back blocked on a
+/// dependency) from a plain `Idle`, and `Stopped` (the slot's loop has
+/// exited for good, there being no more ready work).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerSlotState {
+    Idle,
+    Busy,
+    Blocked,
+    Stopped,
+}
+
+/// Live per-slot detail behind [`WorkerState::get_worker_info`] - what a
+/// worker is doing right now, plus how it's trending (completed/failed
+/// counts, last error), so an operator can spot a worker that's stuck or
+/// has started failing every task it picks up.
+#[derive(Debug, Clone)]
+pub struct WorkerInfo {
+    pub worker_id: String,
+    pub status: WorkerSlotState,
+    pub current_task: Option<String>,
+    pub tasks_completed: u64,
+    pub tasks_failed: u64,
+    pub last_error: Option<String>,
+    pub busy_since: Option<Instant>,
+}
+
+impl WorkerInfo {
+    fn idle(worker_id: &str) -> Self {
+        Self {
+            worker_id: worker_id.to_string(),
+            status: WorkerSlotState::Idle,
+            current_task: None,
+            tasks_completed: 0,
+            tasks_failed: 0,
+            last_error: None,
+            busy_since: None,
+        }
+    }
+}
+
+/// JSON-safe snapshot of a [`WorkerInfo`], for cross-process use - e.g.
+/// `drover worker list` querying a live run over the control socket - where
+/// `Instant` can't cross the wire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerInfoSnapshot {
+    pub worker_id: String,
+    pub status: WorkerSlotState,
+    pub current_task: Option<String>,
+    pub tasks_completed: u64,
+    pub tasks_failed: u64,
+    pub last_error: Option<String>,
+    pub busy_for: Option<Duration>,
+}
+
+/// How a worker's just-finished task turned out, for updating its
+/// [`WorkerInfo`] in [`WorkerState::release_task`].
+pub enum WorkerTaskOutcome {
+    Completed,
+    Failed(String),
+    Blocked,
+}
+
 /// Shared state between workers and orchestrator
 #[derive(Clone)]
 pub struct WorkerState {
     inner: Arc<RwLock<WorkerStateInner>>,
+    /// The run's live concurrency target, set once via
+    /// [`Self::set_concurrency`] - an `OnceLock` rather than a plain field so
+    /// every clone of this handle sees the same watch once it's wired in.
+    concurrency: Arc<std::sync::OnceLock<watch::Receiver<usize>>>,
 }
 
 struct WorkerStateInner {
     tasks: Vec<Task>,
     current_assignments: std::collections::HashMap<String, String>, // worker_id -> task_id
+    /// Each registered worker's slot index and private assignment channel -
+    /// the push-model counterpart to `current_assignments`. A worker with no
+    /// entry here hasn't registered (or has been torn down); one with an
+    /// entry but no `current_assignments` row is free and eligible for
+    /// [`WorkerState::assign_ready_tasks`], provided its index is still
+    /// within the live concurrency target.
+    assignment_tx: std::collections::HashMap<String, (usize, mpsc::Sender<Task>)>,
+    infos: std::collections::HashMap<String, WorkerInfo>,
 }
 
 impl WorkerState {
@@ -40,34 +231,178 @@ impl WorkerState {
             inner: Arc::new(RwLock::new(WorkerStateInner {
                 tasks,
                 current_assignments: Default::default(),
+                assignment_tx: Default::default(),
+                infos: Default::default(),
             })),
+            concurrency: Arc::new(std::sync::OnceLock::new()),
         }
     }
 
-    /// Try to claim a ready task
-    pub async fn claim_task(&self, worker_id: &str) -> Option<Task> {
+    /// Wire in the run's live concurrency target, so [`Self::assign_ready_tasks`]
+    /// stops handing out work to slots scaled below by `drover concurrency
+    /// <n>`. Set once, right after the control channel that owns the watch
+    /// is created - before that point every slot is treated as in range.
+    pub fn set_concurrency(&self, concurrency: watch::Receiver<usize>) {
+        let _ = self.concurrency.set(concurrency);
+    }
+
+    /// Register a new worker slot and hand back its assignment channel -
+    /// the push-model counterpart to the old claim-on-poll loop. The
+    /// worker's run loop just waits on this receiver instead of repeatedly
+    /// asking for work; it's woken the moment [`Self::assign_ready_tasks`]
+    /// has something for it. `index` is the slot's position among
+    /// `max_workers`, checked against the live concurrency target before
+    /// it's ever handed work.
+    pub async fn register(&self, worker_id: &str, index: usize) -> mpsc::Receiver<Task> {
+        // Capacity 1: a worker is never handed a second task before it's
+        // finished (or given up on) its first.
+        let (tx, rx) = mpsc::channel(1);
+
         let mut inner = self.inner.write().await;
+        inner.assignment_tx.insert(worker_id.to_string(), (index, tx));
+        inner.infos.entry(worker_id.to_string()).or_insert_with(|| WorkerInfo::idle(worker_id));
+        drop(inner);
 
-        // Find highest priority ready task that's not claimed
-        let task_idx = inner.tasks.iter().enumerate()
-            .filter(|(_, t)| t.status == TaskStatus::Ready)
-            .filter(|(_, t)| !inner.current_assignments.values().any(|id| id == &t.id))
-            .max_by_key(|(_, t)| t.priority)
-            .map(|(i, _)| i);
-
-        if let Some(idx) = task_idx {
-            let task = inner.tasks[idx].clone();
-            inner.current_assignments.insert(worker_id.to_string(), task.id.clone());
-            Some(task)
-        } else {
-            None
+        self.assign_ready_tasks().await;
+        rx
+    }
+
+    /// Task-first scheduling pass: match every free, registered worker
+    /// within the live concurrency target to the highest-priority
+    /// unassigned `Ready` task (same critical-path-then-priority ordering
+    /// the old pull-based `claim_task` used) and push it straight down that
+    /// worker's assignment channel, repeating until either runs out. Call
+    /// this any time a change could create a new match: a task turning
+    /// `Ready`, a worker's task being released, or the concurrency target
+    /// changing.
+    pub async fn assign_ready_tasks(&self) {
+        let concurrency_limit = self.concurrency.get().map_or(usize::MAX, |rx| *rx.borrow());
+
+        loop {
+            let (worker_id, task, tx) = {
+                let mut inner = self.inner.write().await;
+
+                let Some(worker_id) = inner.assignment_tx.iter()
+                    .find(|(id, (index, _))| *index < concurrency_limit && !inner.current_assignments.contains_key(*id))
+                    .map(|(id, _)| id.clone())
+                else {
+                    return;
+                };
+
+                let task_idx = inner.tasks.iter().enumerate()
+                    .filter(|(_, t)| t.status == TaskStatus::Ready)
+                    .filter(|(_, t)| !inner.current_assignments.values().any(|id| id == &t.id))
+                    .max_by_key(|(_, t)| (t.critical_path_rank, t.priority))
+                    .map(|(i, _)| i);
+
+                let Some(idx) = task_idx else {
+                    return;
+                };
+
+                let task = inner.tasks[idx].clone();
+                inner.current_assignments.insert(worker_id.clone(), task.id.clone());
+
+                let info = inner.infos.entry(worker_id.clone()).or_insert_with(|| WorkerInfo::idle(&worker_id));
+                info.status = WorkerSlotState::Busy;
+                info.current_task = Some(task.id.clone());
+                info.busy_since = Some(Instant::now());
+
+                let tx = inner.assignment_tx.get(&worker_id).map(|(_, tx)| tx.clone())
+                    .expect("worker_id came from assignment_tx's own keys");
+                (worker_id, task, tx)
+            };
+
+            if tx.send(task).await.is_err() {
+                // The worker's receiver is gone (its loop already exited) -
+                // undo the assignment and let the next pass try someone else.
+                let mut inner = self.inner.write().await;
+                inner.current_assignments.remove(&worker_id);
+                inner.assignment_tx.remove(&worker_id);
+            }
         }
     }
 
-    /// Release a task claim
-    pub async fn release_task(&self, worker_id: &str) {
+    /// Flip a task's tracked status - keeps the scheduler's view in sync
+    /// with [`crate::drover::Drover`]'s own task state as it reaches a
+    /// terminal or waiting status. Triggers an assignment pass when the new
+    /// status is `Ready`, since that's the transition that can free up work
+    /// for an idle worker.
+    pub async fn set_task_status(&self, task_id: &str, status: TaskStatus) {
+        {
+            let mut inner = self.inner.write().await;
+            if let Some(task) = inner.tasks.iter_mut().find(|t| t.id == task_id) {
+                task.status = status;
+            }
+        }
+
+        if status == TaskStatus::Ready {
+            self.assign_ready_tasks().await;
+        }
+    }
+
+    /// Add a task that didn't exist when the pool's task list was first
+    /// seeded - e.g. an auto-created "unblock" task - and run an assignment
+    /// pass in case it's immediately `Ready`.
+    pub async fn push_task(&self, task: Task) {
+        let status = task.status;
+        {
+            let mut inner = self.inner.write().await;
+            inner.tasks.push(task);
+        }
+
+        if status == TaskStatus::Ready {
+            self.assign_ready_tasks().await;
+        }
+    }
+
+    /// Close every worker's assignment channel, so one sitting on
+    /// `rx.recv()` waiting for more work wakes up with `None` and exits on
+    /// its own instead of needing to be `abort()`ed mid-wait. Call once the
+    /// run is complete.
+    pub async fn close(&self) {
         let mut inner = self.inner.write().await;
-        inner.current_assignments.remove(worker_id);
+        inner.assignment_tx.clear();
+    }
+
+    /// Release a task claim, recording how it turned out on the worker's
+    /// [`WorkerInfo`], then run an assignment pass in case other `Ready`
+    /// work is waiting for this now-free worker.
+    pub async fn release_task(&self, worker_id: &str, outcome: WorkerTaskOutcome) {
+        {
+            let mut inner = self.inner.write().await;
+            inner.current_assignments.remove(worker_id);
+
+            let info = inner.infos.entry(worker_id.to_string()).or_insert_with(|| WorkerInfo::idle(worker_id));
+            info.current_task = None;
+            info.busy_since = None;
+
+            match outcome {
+                WorkerTaskOutcome::Completed => {
+                    info.tasks_completed += 1;
+                    info.status = WorkerSlotState::Idle;
+                }
+                WorkerTaskOutcome::Failed(error) => {
+                    info.tasks_failed += 1;
+                    info.last_error = Some(error);
+                    info.status = WorkerSlotState::Idle;
+                }
+                WorkerTaskOutcome::Blocked => {
+                    info.status = WorkerSlotState::Blocked;
+                }
+            }
+        }
+
+        self.assign_ready_tasks().await;
+    }
+
+    /// Mark a worker's slot as stopped for good - its loop found no more
+    /// ready work and exited.
+    pub async fn mark_stopped(&self, worker_id: &str) {
+        let mut inner = self.inner.write().await;
+        let info = inner.infos.entry(worker_id.to_string()).or_insert_with(|| WorkerInfo::idle(worker_id));
+        info.status = WorkerSlotState::Stopped;
+        info.current_task = None;
+        info.busy_since = None;
     }
 
     /// Get count of ready tasks
@@ -77,6 +412,43 @@ impl WorkerState {
             .filter(|t| t.status == TaskStatus::Ready)
             .count()
     }
+
+    /// Live per-worker info for every slot that has claimed at least one
+    /// task so far, keyed by worker ID.
+    pub async fn get_worker_info(&self) -> std::collections::HashMap<String, WorkerInfo> {
+        let inner = self.inner.read().await;
+        inner.infos.clone()
+    }
+
+    /// The subset of [`get_worker_info`](Self::get_worker_info) currently
+    /// holding a task - the workers an operator usually cares about when
+    /// checking for stuck work.
+    pub async fn busy_workers(&self) -> std::collections::HashMap<String, WorkerInfo> {
+        let inner = self.inner.read().await;
+        inner.infos.iter()
+            .filter(|(_, info)| info.status == WorkerSlotState::Busy)
+            .map(|(id, info)| (id.clone(), info.clone()))
+            .collect()
+    }
+
+    /// JSON-safe snapshot of every worker's info, sorted by ID - for
+    /// [`WorkerInfoSnapshot`] consumers like the control socket.
+    pub async fn worker_info_snapshot(&self) -> Vec<WorkerInfoSnapshot> {
+        let inner = self.inner.read().await;
+        let mut rows: Vec<WorkerInfoSnapshot> = inner.infos.values()
+            .map(|info| WorkerInfoSnapshot {
+                worker_id: info.worker_id.clone(),
+                status: info.status,
+                current_task: info.current_task.clone(),
+                tasks_completed: info.tasks_completed,
+                tasks_failed: info.tasks_failed,
+                last_error: info.last_error.clone(),
+                busy_for: info.busy_since.map(|t| t.elapsed()),
+            })
+            .collect();
+        rows.sort_by(|a, b| a.worker_id.cmp(&b.worker_id));
+        rows
+    }
 }
 
 /// Pool of worker tasks
@@ -84,6 +456,11 @@ pub struct WorkerPool {
     config: WorkerPoolConfig,
     state: WorkerState,
     event_tx: mpsc::Sender<WorkerEvent>,
+    manager: WorkerManager,
+    /// Shared sliding window of recent task durations backing each worker's
+    /// [`tranquility::Tranquilizer`] - one per pool, not per worker, so the
+    /// average reflects the whole pool's recent pace.
+    duration_tracker: tranquility::TaskDurationTracker,
 }
 
 #[derive(Clone)]
@@ -92,21 +469,52 @@ pub struct WorkerPoolConfig {
     pub task_timeout: Duration,
     pub project_dir: PathBuf,
     pub worktree_dir: PathBuf,
+    /// Directory this run's per-task log files are captured under - see
+    /// [`tasklog::TaskLogWriter`].
+    pub log_dir: PathBuf,
+    pub log_max_bytes: u64,
+    pub log_max_backups: u32,
+    /// Durable store and run this pool belongs to, so each worker can
+    /// heartbeat - letting a separate `drover workers` process see live
+    /// status even though it has no handle into this one.
+    pub store: DurableStore,
+    pub run_id: Uuid,
+    /// Pause/resume/cancel signal from the run's control channel.
+    pub control: watch::Receiver<RunSignal>,
+    /// Live tranquility multiplier from the run's control channel - see
+    /// [`tranquility::Tranquilizer`].
+    pub tranquility: watch::Receiver<f64>,
+    /// Live worker concurrency target from the run's control channel - a
+    /// slot whose index falls outside this count idles instead of claiming.
+    /// Seeded at `max_workers` since the pool spawns exactly that many
+    /// slots and can only ever shrink or grow back up to it.
+    pub concurrency: watch::Receiver<usize>,
 }
 
 impl WorkerPool {
+    /// `manager` is caller-owned (rather than created fresh here) so the
+    /// orchestrator can keep its own handle and query worker status while
+    /// the pool is running - see [`crate::drover::Drover::worker_status`].
     pub fn new(
         config: WorkerPoolConfig,
         state: WorkerState,
         event_tx: mpsc::Sender<WorkerEvent>,
+        manager: WorkerManager,
     ) -> Result<Self> {
         Ok(Self {
             config,
             state,
             event_tx,
+            manager,
+            duration_tracker: tranquility::TaskDurationTracker::new(),
         })
     }
 
+    /// The live status tracker for this pool's workers.
+    pub fn manager(&self) -> WorkerManager {
+        self.manager.clone()
+    }
+
     /// Spawn worker tasks
     pub async fn spawn_workers(&self) -> Result<Vec<tokio::task::JoinHandle<()>>> {
         let mut handles = vec![];
@@ -114,74 +522,93 @@ impl WorkerPool {
         for i in 0..self.config.max_workers {
             let worker_id = format!("worker-{}", i);
             let state = self.state.clone();
+            let worker_info_state = state.clone();
+            let assignments = state.register(&worker_id, i).await;
             let event_tx = self.event_tx.clone();
             let config = self.config.clone();
-            let executor = TaskExecutor::new(config.project_dir.clone(), config.worktree_dir.clone());
+            let manager = self.manager.clone();
+            let mut worker = ClaudeWorker::new(
+                worker_id.clone(),
+                config.project_dir.clone(),
+                config.worktree_dir.clone(),
+                config.log_dir.clone(),
+                config.log_max_bytes,
+                config.log_max_backups,
+                config.task_timeout,
+                state,
+                assignments,
+                event_tx,
+                config.tranquility.clone(),
+                self.duration_tracker.clone(),
+            );
+
+            let mut control = config.control.clone();
+            let tranquility = config.tranquility.clone();
+            let mut concurrency = config.concurrency.clone();
 
             let handle = tokio::spawn(async move {
                 tracing::info!("Worker {} started", worker_id);
 
-                loop {
-                    // Try to claim a task
-                    let task = match state.claim_task(&worker_id).await {
-                        Some(t) => t,
-                        None => {
-                            // No work available, sleep and check again
-                            tokio::time::sleep(Duration::from_secs(5)).await;
-
-                            // Check if there's any work at all
-                            if state.ready_count().await == 0 {
-                                tracing::debug!("Worker {} exiting - no work", worker_id);
-                                break;
+                'outer: loop {
+                    // Respect pause/cancel/concurrency before claiming new
+                    // work - a worker never abandons a task mid-flight, it
+                    // just waits here before picking up the next one.
+                    loop {
+                        match *control.borrow() {
+                            RunSignal::Cancelled => break 'outer,
+                            RunSignal::Paused => {
+                                if control.changed().await.is_err() {
+                                    break 'outer;
+                                }
+                                continue;
                             }
-                            continue;
+                            RunSignal::Running => {}
                         }
-                    };
-
-                    tracing::info!("Worker {} claimed task {}", worker_id, task.id);
-
-                    // Execute the task
-                    let result: Result<Duration, anyhow::Error> = executor.execute(&task).await;
 
-                    // Release the claim
-                    state.release_task(&worker_id).await;
-
-                    // Send event
-                    match result {
-                        Ok(duration) => {
-                            let _ = event_tx.send(WorkerEvent::TaskCompleted {
-                                task_id: task.id.clone(),
-                                duration,
-                            }).await;
+                        if i < *concurrency.borrow() {
+                            break;
                         }
-                        Err(e) => {
-                            let error_msg = e.to_string();
-                            let retriable = !error_msg.contains("blocked");
-
-                            if retriable {
-                                let _ = event_tx.send(WorkerEvent::TaskFailed {
-                                    task_id: task.id.clone(),
-                                    error: error_msg,
-                                    retriable: true,
-                                }).await;
-                            } else {
-                                // Check if it's a blocking error
-                                if error_msg.contains("blocked by") {
-                                    let blockers = extract_blockers(&error_msg);
-                                    let _ = event_tx.send(WorkerEvent::TaskBlocked {
-                                        task_id: task.id.clone(),
-                                        blocked_by: blockers,
-                                    }).await;
-                                } else {
-                                    let _ = event_tx.send(WorkerEvent::TaskFailed {
-                                        task_id: task.id.clone(),
-                                        error: error_msg,
-                                        retriable: false,
-                                    }).await;
+
+                        // Scaled down below this slot's index - wait for
+                        // either the target to rise back up or a control
+                        // signal, whichever comes first.
+                        tokio::select! {
+                            _ = concurrency.changed() => {}
+                            result = control.changed() => {
+                                if result.is_err() {
+                                    break 'outer;
                                 }
                             }
                         }
                     }
+
+                    // Report idle before parking on the assignment channel -
+                    // `worker.run()` now blocks until it's actually handed a
+                    // task, so this is the one chance to tell the
+                    // manager/dashboard this slot is waiting rather than
+                    // leaving its last-reported activity stuck at `Busy`.
+                    manager.report(&worker_id, WorkerActivity::Idle, worker.status()).await;
+
+                    let activity = worker.run().await;
+                    manager.report(&worker_id, activity, worker.status()).await;
+
+                    let current_tranquility = *tranquility.borrow();
+                    if let Err(e) = config.store.heartbeat(&config.run_id, &worker_id, current_tranquility).await {
+                        tracing::warn!(worker_id = %worker_id, error = %e, "Failed to record heartbeat");
+                    }
+
+                    match activity {
+                        WorkerActivity::Done => {
+                            tracing::debug!("Worker {} exiting - no work", worker_id);
+                            worker_info_state.mark_stopped(&worker_id).await;
+                            break;
+                        }
+                        // `worker.run()` itself now blocks on its assignment
+                        // channel rather than polling, so it never actually
+                        // returns `Idle` - only the proactive report above
+                        // does. Nothing to do here either way.
+                        WorkerActivity::Idle | WorkerActivity::Busy => {}
+                    }
                 }
 
                 tracing::info!("Worker {} stopped", worker_id);
@@ -195,10 +622,59 @@ impl WorkerPool {
 }
 
 /// Extract blocker IDs from an error message
-fn extract_blockers(msg: &str) -> Vec<String> {
+pub(crate) fn extract_blockers(msg: &str) -> Vec<String> {
     // Simple parsing - look for bead IDs like "bd-abc123"
     let re = regex::Regex::new(r"bd-[a-z0-9]+").unwrap();
     re.find_iter(msg)
         .map(|m: regex::Match| m.as_str().to_string())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drover::BackoffStrategy;
+
+    fn ready_task(id: &str) -> Task {
+        Task {
+            id: id.to_string(),
+            title: id.to_string(),
+            description: None,
+            priority: 0,
+            status: TaskStatus::Ready,
+            parent_epic: None,
+            blocked_by: vec![],
+            labels: vec![],
+            attempts: 0,
+            last_error: None,
+            progress: None,
+            max_attempts: 1,
+            next_retry_at: None,
+            backoff: BackoffStrategy::Fixed(Duration::from_secs(0)),
+            critical_path_rank: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn assign_ready_tasks_skips_slots_scaled_below_concurrency() {
+        let state = WorkerState::new(vec![ready_task("t1"), ready_task("t2")]);
+        let (concurrency_tx, concurrency_rx) = watch::channel(1usize);
+        state.set_concurrency(concurrency_rx);
+
+        // Two slots register, but concurrency is pinned at 1 - only slot 0
+        // should ever be eligible for a push.
+        let mut rx0 = state.register("worker-0", 0).await;
+        let mut rx1 = state.register("worker-1", 1).await;
+
+        let got0 = rx0.try_recv();
+        let got1 = rx1.try_recv();
+        assert!(got0.is_ok(), "in-range slot 0 should have been pushed a task");
+        assert!(got1.is_err(), "out-of-range slot 1 should have been left idle");
+
+        // Scaling back up should make slot 1 eligible without any other
+        // trigger event.
+        concurrency_tx.send(2).unwrap();
+        state.assign_ready_tasks().await;
+        assert!(rx1.try_recv().is_ok(), "slot 1 should be pushed work once concurrency allows it");
+    }
+}
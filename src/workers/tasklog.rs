@@ -0,0 +1,130 @@
+//! Per-task log capture with size-based rotation
+//!
+//! Each task's Claude Code invocation writes its captured stdout/stderr to
+//! `<log_dir>/<task_id>.log` via [`TaskLogWriter`], bracketed by start/end
+//! headers recording timestamps and the eventual exit status. Once a log
+//! file grows past `max_bytes` it's rotated: the current file is
+//! gzip-compressed into `<task_id>.log.1.gz`, any existing `.N.gz`
+//! generations shift up by one, and the oldest beyond `max_backups` is
+//! dropped - so `drover logs` keeps working on a long-lived project
+//! without its log directory growing unbounded.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+/// Where `task_id`'s log lives under `run_dir`, matching the path
+/// [`TaskLogWriter::open`] writes to.
+pub fn log_path(run_dir: &Path, task_id: &str) -> PathBuf {
+    run_dir.join(format!("{task_id}.log"))
+}
+
+/// Read back `task_id`'s persisted log in full, for `drover logs`.
+///
+/// Only returns the live log file - rotated-out `.N.gz` generations are
+/// history, not part of "the" log, so they're left alone here.
+pub fn get_task_log(run_dir: &Path, task_id: &str) -> Result<String> {
+    let path = log_path(run_dir, task_id);
+    fs::read_to_string(&path)
+        .with_context(|| format!("No log found for task {task_id} at {}", path.display()))
+}
+
+/// Captures one task's output to `<log_dir>/<task_id>.log`, rotating it
+/// first if it's grown past `max_bytes`.
+pub struct TaskLogWriter {
+    file: File,
+    path: PathBuf,
+}
+
+impl TaskLogWriter {
+    /// Open (rotating first if oversized) the log file for `task_id`
+    /// within `run_dir`, creating `run_dir` if it doesn't exist yet.
+    pub fn open(run_dir: &Path, task_id: &str, max_bytes: u64, max_backups: u32) -> Result<Self> {
+        fs::create_dir_all(run_dir)
+            .with_context(|| format!("Failed to create log directory {}", run_dir.display()))?;
+
+        let path = log_path(run_dir, task_id);
+        rotate_if_oversized(&path, max_bytes, max_backups)?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open log file {}", path.display()))?;
+
+        Ok(Self { file, path })
+    }
+
+    /// Write a start-of-task header.
+    pub fn start(&mut self, task_id: &str) -> Result<()> {
+        writeln!(self.file, "=== {} started {} ===", task_id, chrono::Utc::now().to_rfc3339())?;
+        Ok(())
+    }
+
+    /// Append one line of captured stdout/stderr.
+    pub fn write_line(&mut self, line: &str) -> Result<()> {
+        writeln!(self.file, "{line}")?;
+        Ok(())
+    }
+
+    /// Write an end-of-task footer recording how the task finished.
+    pub fn finish(&mut self, status: &str) -> Result<()> {
+        writeln!(self.file, "=== finished {} - {} ===", chrono::Utc::now().to_rfc3339(), status)?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Rotate `path` if it exists and is at least `max_bytes`: gzip it into
+/// generation 1, shifting any existing generations up to `max_backups` and
+/// dropping whatever falls off the end.
+fn rotate_if_oversized(path: &Path, max_bytes: u64, max_backups: u32) -> Result<()> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(());
+    };
+
+    if metadata.len() < max_bytes {
+        return Ok(());
+    }
+
+    if max_backups == 0 {
+        fs::remove_file(path)?;
+        return Ok(());
+    }
+
+    let _ = fs::remove_file(backup_path(path, max_backups));
+
+    for generation in (1..max_backups).rev() {
+        let from = backup_path(path, generation);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, generation + 1))?;
+        }
+    }
+
+    compress_to(path, &backup_path(path, 1))?;
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+fn backup_path(path: &Path, generation: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{generation}.gz"));
+    PathBuf::from(name)
+}
+
+fn compress_to(src: &Path, dest: &Path) -> Result<()> {
+    let mut contents = Vec::new();
+    File::open(src)?.read_to_end(&mut contents)?;
+
+    let mut encoder = GzEncoder::new(File::create(dest)?, Compression::default());
+    encoder.write_all(&contents)?;
+    encoder.finish()?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! Tranquility-based throttling between task claims
+//!
+//! Instead of the orchestrator's fixed `poll_interval`, each worker paces
+//! itself: after finishing a task it sleeps `recent_avg_task_duration *
+//! tranquility` before claiming the next one. A tranquility of 0 runs flat
+//! out; 4 sleeps four times as long as tasks have recently been taking. This
+//! backs off smoothly under load (slow tasks, likely hitting a rate limit,
+//! earn a longer pause) instead of inserting the same delay regardless of
+//! how busy the backend is - and averaging over a window of recent tasks
+//! rather than just the one that happened to finish keeps one unusually
+//! fast or slow task from swinging the pacing on its own.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// Never sleep longer than this, regardless of tranquility or recent task
+/// durations, so one very slow task can't stall a worker indefinitely.
+const MAX_SLEEP: Duration = Duration::from_secs(300);
+
+/// How many of the most recently completed tasks' durations to average
+/// over.
+const WINDOW_SIZE: usize = 10;
+
+/// Sliding window of recent task durations, shared by every worker in a
+/// pool, backing [`Tranquilizer::tranquilize`]'s pacing. Shared rather than
+/// per-worker so a pool with several slots still gets a representative
+/// average instead of each slot timing only its own sparse history.
+#[derive(Clone)]
+pub struct TaskDurationTracker {
+    durations: Arc<RwLock<VecDeque<Duration>>>,
+}
+
+impl TaskDurationTracker {
+    pub fn new() -> Self {
+        Self {
+            durations: Arc::new(RwLock::new(VecDeque::with_capacity(WINDOW_SIZE))),
+        }
+    }
+
+    async fn record(&self, duration: Duration) {
+        let mut durations = self.durations.write().await;
+        durations.push_back(duration);
+        if durations.len() > WINDOW_SIZE {
+            durations.pop_front();
+        }
+    }
+
+    /// The average over whatever's currently in the window - zero before
+    /// any task has completed yet, so the very first claim isn't delayed.
+    async fn recent_average(&self) -> Duration {
+        let durations = self.durations.read().await;
+        if durations.is_empty() {
+            return Duration::ZERO;
+        }
+        durations.iter().sum::<Duration>() / durations.len() as u32
+    }
+}
+
+impl Default for TaskDurationTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Times a task, then sleeps a tranquility-scaled multiple of the recent
+/// average task duration before the caller claims its next one.
+pub struct Tranquilizer {
+    start: Instant,
+}
+
+impl Tranquilizer {
+    /// Start timing a task.
+    pub fn start() -> Self {
+        Self { start: Instant::now() }
+    }
+
+    /// Record this task's duration into `tracker`, then sleep
+    /// `tranquility * recent_avg_task_duration`, capped at [`MAX_SLEEP`]. A
+    /// non-positive `tranquility` skips the sleep entirely (but the
+    /// duration is still recorded, so the window stays current for the
+    /// next worker to sleep by).
+    pub async fn tranquilize(self, tranquility: f64, tracker: &TaskDurationTracker) {
+        tracker.record(self.start.elapsed()).await;
+
+        if !tranquility.is_finite() || tranquility <= 0.0 {
+            return;
+        }
+
+        let sleep = tracker.recent_average().await.mul_f64(tranquility).min(MAX_SLEEP);
+        if !sleep.is_zero() {
+            tokio::time::sleep(sleep).await;
+        }
+    }
+}